@@ -1,4 +1,6 @@
-//! A module containing the `ChildIterator` trait
+//! A module containing the `ChildIterator` trait and the tools to navigate along chains of child iterators
+
+use std::fmt;
 
 /// A trait that means `Self` is an iterator on another iterator we could access to in some ways
 pub trait ChildIterator {
@@ -12,4 +14,362 @@ pub trait ChildIterator {
 
     /// Returns a reference to the parent of `self`
     fn get_parent(&self) -> &Self::Parent;
+
+    /// Replaces the parent of `self` by `new` and returns the previous one. The configuration of `self` is kept
+    fn replace_parent(&mut self, new: Self::Parent) -> Self::Parent {
+        std::mem::replace(self.get_parent_mut(), new)
+    }
+}
+
+/// A trait allowing to move the configuration of an adapter to another parent, even of another type
+/// 
+/// # Example
+/// ```
+/// use iterators_collection::child::WithParent;
+/// use iterators_collection::filter::Exclude;
+/// 
+/// let iter = Exclude::with_blacklist(vec![1, 2, 3].into_iter(), vec![2]);
+/// 
+/// // The blacklist is kept
+/// let iter = iter.with_parent(0..5);
+/// assert_eq!(iter.collect::<Vec<i32>>(), vec![0, 1, 3, 4]);
+/// ```
+pub trait WithParent<P>: ChildIterator
+where
+    P: Iterator,
+{
+    /// The type of the adapter once its parent replaced
+    type Output: ChildIterator<Parent = P>;
+
+    /// Destroys `self` and returns the same adapter on `parent`
+    fn with_parent(self, parent: P) -> Self::Output;
+}
+
+/// A trait giving access to the first iterator of a chain of `ChildIterator`s, called the root, without calling `get_parent` once per adapter
+/// 
+/// Each `ChildIterator` of this crate implements it by asking its parent, and the iterators which are not `ChildIterator`s, like the ones of the standard library, are their own root. Use `impl_root_iterator!` to make your own iterators roots, and `impl_child_iterator!` to implement it for your adapters
+/// 
+/// # Example
+/// ```
+/// use iterators_collection::child::RootIterator;
+/// use iterators_collection::filter::Exclude;
+/// use iterators_collection::{IntoResettable, ResettableIterator};
+/// 
+/// let iter = (0..10).resettable().resettable_map(|x| x * 2);
+/// let mut iter = Exclude::with_blacklist(iter, vec![4]);
+/// 
+/// assert_eq!(iter.depth(), 3);
+/// assert_eq!(iter.root(), &(0..10));
+/// 
+/// iter.root_mut().next();
+/// assert_eq!(iter.release_root(), 1..10);
+/// ```
+pub trait RootIterator: Iterator {
+    /// The type of the first iterator of the chain
+    type Root: Iterator;
+
+    /// Returns a reference to the root of `self`
+    fn root(&self) -> &Self::Root;
+
+    /// Returns a mutable reference to the root of `self`
+    fn root_mut(&mut self) -> &mut Self::Root;
+
+    /// Destroys `self` and returns its root
+    fn release_root(self) -> Self::Root;
+
+    /// Returns the number of `ChildIterator`s between `self` and its root. It is 0 for a root
+    fn depth(&self) -> usize;
+}
+
+/// A trait rendering the structure of a chain of iterators, from the outermost adapter to the root
+/// 
+/// Each stage writes its own description, usually the name of its type, and gives access to its parent if it has one. The whole chain is then displayed with `describe`
+/// 
+/// # Example
+/// ```
+/// use iterators_collection::child::Describe;
+/// use iterators_collection::filter::Exclude;
+/// use iterators_collection::{IntoResettable, ResettableIterator};
+/// 
+/// let iter = (0..10).resettable().resettable_map(|x| x * 2);
+/// let iter = Exclude::with_blacklist(iter, vec![4, 8]);
+/// 
+/// assert_eq!(
+///     iter.describe().to_string(),
+///     "Exclude(blacklist=2) <- ResettableMap <- Restartable <- Range",
+/// );
+/// ```
+pub trait Describe {
+    /// Writes the description of this stage only. The default implementation writes the name of the type without its path and its generic parameters
+    fn describe_stage(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(short_type_name(std::any::type_name::<Self>()))
+    }
+
+    /// Returns the parent of this stage, or `None` if it is the root of the chain
+    fn describe_parent(&self) -> Option<&dyn Describe> {
+        None
+    }
+
+    /// Returns an object displaying the whole chain, each stage separated from its parent by ` <- `
+    fn describe(&self) -> Description<'_>
+    where
+        Self: Sized,
+    {
+        Description { stage: self }
+    }
+}
+
+/// The `Display`able description of a chain of iterators, returned by `Describe::describe`
+#[derive(Clone, Copy)]
+pub struct Description<'a> {
+    stage: &'a dyn Describe,
+}
+
+impl fmt::Display for Description<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.stage.describe_stage(f)?;
+
+        let mut parent = self.stage.describe_parent();
+        while let Some(stage) = parent {
+            f.write_str(" <- ")?;
+            stage.describe_stage(f)?;
+            parent = stage.describe_parent();
+        }
+
+        Ok(())
+    }
 }
+
+impl fmt::Debug for Description<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+/// Strips the path and the generic parameters of a type name
+fn short_type_name(name: &str) -> &str {
+    let name = match name.find('<') {
+        Some(index) => &name[..index],
+        None        => name,
+    };
+
+    match name.rfind("::") {
+        Some(index) => &name[index + 2..],
+        None        => name,
+    }
+}
+
+/// Implements `ChildIterator` for an adapter storing its parent in one of its fields
+/// 
+/// The syntax is `impl_child_iterator!([generics] Type, field: ParentType)`, optionally followed by `, where` and some bounds. The parent type is required to implement `Iterator`
+/// 
+/// It also implements `RootIterator` when the parent implements it
+/// 
+/// # Example
+/// ```
+/// use iterators_collection::child::ChildIterator;
+/// use iterators_collection::impl_child_iterator;
+/// 
+/// struct Doubled<I> {
+///     inner: I,
+/// }
+/// 
+/// impl<I> Iterator for Doubled<I>
+/// where
+///     I: Iterator<Item = u32>,
+/// {
+///     type Item = u32;
+/// 
+///     fn next(&mut self) -> Option<u32> {
+///         self.inner.next().map(|x| x * 2)
+///     }
+/// }
+/// 
+/// impl_child_iterator!([I] Doubled<I>, inner: I, where I: Iterator<Item = u32>);
+/// 
+/// let iter = Doubled { inner: 0..3 };
+/// assert_eq!(iter.get_parent(), &(0..3));
+/// ```
+#[macro_export]
+macro_rules! impl_child_iterator {
+    ([$($generics:tt)*] $type:ty, $field:ident: $parent:ty $(, where $($bounds:tt)*)?) => {
+        impl<$($generics)*> $crate::child::ChildIterator for $type
+        where
+            $parent: ::core::iter::Iterator,
+            $($($bounds)*)?
+        {
+            type Parent = $parent;
+
+            fn release_parent(self) -> $parent {
+                self.$field
+            }
+
+            fn get_parent_mut(&mut self) -> &mut $parent {
+                &mut self.$field
+            }
+
+            fn get_parent(&self) -> &$parent {
+                &self.$field
+            }
+        }
+
+        impl<$($generics)*> $crate::child::RootIterator for $type
+        where
+            $type: ::core::iter::Iterator,
+            $parent: $crate::child::RootIterator,
+            $($($bounds)*)?
+        {
+            type Root = <$parent as $crate::child::RootIterator>::Root;
+
+            fn root(&self) -> &Self::Root {
+                $crate::child::RootIterator::root(&self.$field)
+            }
+
+            fn root_mut(&mut self) -> &mut Self::Root {
+                $crate::child::RootIterator::root_mut(&mut self.$field)
+            }
+
+            fn release_root(self) -> Self::Root {
+                $crate::child::RootIterator::release_root(self.$field)
+            }
+
+            fn depth(&self) -> usize {
+                $crate::child::RootIterator::depth(&self.$field) + 1
+            }
+        }
+    };
+}
+
+/// Implements `RootIterator` for an iterator which is not a `ChildIterator`, making it its own root
+/// 
+/// It also implements `Describe`, describing the iterator by the name of its type
+/// 
+/// The syntax is `impl_root_iterator!([generics] Type)`, optionally followed by `, where` and some bounds
+/// 
+/// # Example
+/// ```
+/// use iterators_collection::child::RootIterator;
+/// use iterators_collection::impl_root_iterator;
+/// 
+/// struct Zeros;
+/// 
+/// impl Iterator for Zeros {
+///     type Item = u32;
+/// 
+///     fn next(&mut self) -> Option<u32> {
+///         Some(0)
+///     }
+/// }
+/// 
+/// impl_root_iterator!([] Zeros);
+/// 
+/// assert_eq!(Zeros.depth(), 0);
+/// ```
+#[macro_export]
+macro_rules! impl_root_iterator {
+    ([$($generics:tt)*] $type:ty $(, where $($bounds:tt)*)?) => {
+        impl<$($generics)*> $crate::child::RootIterator for $type
+        where
+            $type: ::core::iter::Iterator,
+            $($($bounds)*)?
+        {
+            type Root = Self;
+
+            fn root(&self) -> &Self {
+                self
+            }
+
+            fn root_mut(&mut self) -> &mut Self {
+                self
+            }
+
+            fn release_root(self) -> Self {
+                self
+            }
+
+            fn depth(&self) -> usize {
+                0
+            }
+        }
+
+        impl<$($generics)*> $crate::child::Describe for $type
+        where
+            $($($bounds)*)?
+        {
+        }
+    };
+}
+
+/// Implements `Describe` for an adapter storing its parent in one of its fields, using the name of its type as the description of the stage
+/// 
+/// The syntax is the same as `impl_child_iterator!`. The parent type is required to implement `Describe`
+/// 
+/// # Example
+/// ```
+/// use iterators_collection::child::Describe;
+/// use iterators_collection::impl_describe;
+/// 
+/// struct Doubled<I> {
+///     inner: I,
+/// }
+/// 
+/// impl_describe!([I] Doubled<I>, inner: I);
+/// 
+/// let iter = Doubled { inner: 0..3 };
+/// assert_eq!(iter.describe().to_string(), "Doubled <- Range");
+/// ```
+#[macro_export]
+macro_rules! impl_describe {
+    ([$($generics:tt)*] $type:ty, $field:ident: $parent:ty $(, where $($bounds:tt)*)?) => {
+        impl<$($generics)*> $crate::child::Describe for $type
+        where
+            $parent: $crate::child::Describe,
+            $($($bounds)*)?
+        {
+            fn describe_parent(&self) -> ::core::option::Option<&dyn $crate::child::Describe> {
+                ::core::option::Option::Some(&self.$field)
+            }
+        }
+    };
+}
+
+// The iterators of the standard library are roots, even its adapters whose parent is not accessible
+crate::impl_root_iterator!([A] std::ops::Range<A>);
+crate::impl_root_iterator!([A] std::ops::RangeInclusive<A>);
+crate::impl_root_iterator!([A] std::ops::RangeFrom<A>);
+crate::impl_root_iterator!(['a, T] std::slice::Iter<'a, T>);
+crate::impl_root_iterator!(['a, T] std::slice::IterMut<'a, T>);
+crate::impl_root_iterator!([T] std::vec::IntoIter<T>);
+crate::impl_root_iterator!([T, const N: usize] std::array::IntoIter<T, N>);
+crate::impl_root_iterator!(['a, T] std::collections::vec_deque::Iter<'a, T>);
+crate::impl_root_iterator!([T] std::collections::vec_deque::IntoIter<T>);
+crate::impl_root_iterator!(['a] std::str::Chars<'a>);
+crate::impl_root_iterator!(['a] std::str::CharIndices<'a>);
+crate::impl_root_iterator!(['a] std::str::Bytes<'a>);
+crate::impl_root_iterator!(['a] std::str::Lines<'a>);
+crate::impl_root_iterator!(['a] std::str::SplitWhitespace<'a>);
+crate::impl_root_iterator!([T] std::sync::mpsc::IntoIter<T>);
+crate::impl_root_iterator!(['a, T] std::sync::mpsc::Iter<'a, T>);
+crate::impl_root_iterator!([B] std::io::Lines<B>);
+crate::impl_root_iterator!([R] std::io::Bytes<R>);
+crate::impl_root_iterator!([T] std::iter::Empty<T>);
+crate::impl_root_iterator!([T] std::iter::Once<T>);
+crate::impl_root_iterator!([T] std::iter::Repeat<T>);
+crate::impl_root_iterator!([F] std::iter::FromFn<F>);
+crate::impl_root_iterator!([T, F] std::iter::Successors<T, F>);
+crate::impl_root_iterator!([I] std::iter::Cloned<I>);
+crate::impl_root_iterator!([I] std::iter::Copied<I>);
+crate::impl_root_iterator!([I, F] std::iter::Map<I, F>);
+crate::impl_root_iterator!([I, P] std::iter::Filter<I, P>);
+crate::impl_root_iterator!([I, F] std::iter::FilterMap<I, F>);
+crate::impl_root_iterator!([I] std::iter::Enumerate<I>);
+crate::impl_root_iterator!([I] std::iter::Rev<I>);
+crate::impl_root_iterator!([I] std::iter::Skip<I>);
+crate::impl_root_iterator!([I] std::iter::Take<I>);
+crate::impl_root_iterator!([I] std::iter::StepBy<I>);
+crate::impl_root_iterator!([I] std::iter::Peekable<I>, where I: Iterator);
+crate::impl_root_iterator!([A, B] std::iter::Zip<A, B>);
+crate::impl_root_iterator!([A, B] std::iter::Chain<A, B>);
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,96 @@
+//! Unit tests for the child module
+
+use crate::child::*;
+use crate::filter::Exclude;
+use crate::{IntoResettable, ResettableIterator};
+
+#[test]
+fn root_of_a_root_is_itself() {
+    let mut iter = 0..5;
+
+    assert_eq!(iter.depth(), 0);
+    assert_eq!(iter.root(), &(0..5));
+
+    iter.root_mut().next();
+    assert_eq!(iter.release_root(), 1..5);
+}
+
+#[test]
+fn root_goes_through_the_whole_chain() {
+    let array = [1, 2, 3, 4];
+    let iter = array.iter().resettable().resettable_take(3).resettable_map(|x| x * 2);
+    let mut iter = Exclude::with_blacklist(iter, vec![4]);
+
+    assert_eq!(iter.depth(), 4);
+    assert_eq!(iter.root().len(), 4);
+
+    iter.root_mut().next();
+    assert_eq!(iter.next(), Some(6));
+
+    let root = iter.release_root();
+    assert_eq!(root.len(), 1);
+}
+
+#[test]
+fn root_of_share_iterators() {
+    let mut array = [1, 2, 3];
+    let iter = crate::share::DoubleIterator::new(&mut array).resettable_take(2);
+
+    assert_eq!(iter.depth(), 1);
+    assert_eq!(iter.root().size_hint(), (6, Some(6)));
+}
+
+#[test]
+fn replace_parent_keeps_the_configuration() {
+    let mut iter = Exclude::with_blacklist(0..5, vec![1, 3]);
+    iter.next();
+
+    let old = iter.replace_parent(5..5);
+    assert_eq!(old, 1..5);
+    assert_eq!(iter.next(), None);
+
+    iter.replace_parent(0..4);
+    assert_eq!(iter.collect::<Vec<i32>>(), vec![0, 2]);
+}
+
+#[test]
+fn with_parent_changes_the_parent_type() {
+    let mut counter = 0;
+    let iter = (0..3).resettable().resettable_map(move |x| {
+        counter += 1;
+        x * counter
+    });
+
+    let array = [5, 6];
+    let mut iter = iter.with_parent(array.iter().cloned().resettable());
+    assert_eq!(iter.by_ref().collect::<Vec<i32>>(), vec![5, 12]);
+
+    // The closure is moved with its captured state
+    iter.reset();
+    assert_eq!(iter.collect::<Vec<i32>>(), vec![15, 24]);
+}
+
+#[test]
+fn describe_walks_the_whole_chain() {
+    let iter = (0..5).resettable().resettable_filter(|x| x % 2 == 0);
+    let mut iter = crate::PassCounter::new(iter);
+    iter.next();
+
+    assert_eq!(
+        iter.describe().to_string(),
+        "PassCounter(pass=0, items=1) <- ResettableFilter <- Restartable <- Range",
+    );
+
+    iter.reset();
+    assert_eq!(
+        format!("{:?}", iter.describe()),
+        "PassCounter(pass=1, items=0) <- ResettableFilter <- Restartable <- Range",
+    );
+}
+
+#[test]
+fn describe_a_root() {
+    let array = [1, 2, 3];
+    assert_eq!(array.iter().describe().to_string(), "Iter");
+    assert!(array.iter().describe_parent().is_none());
+}
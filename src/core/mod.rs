@@ -5,6 +5,28 @@ pub trait ResettableIterator: Iterator {
     /// Resets the iterator to its initial state when called
     fn reset(&mut self);
 
+    /// Resets the iterator and moves it forward so the next call to `next` yields its `n`th item, starting from 0
+    /// 
+    /// The default implementation calls `reset` then `nth`, but some iterators override it to seek directly
+    /// 
+    /// # Example
+    /// ```
+    /// use iterators_collection::{IntoResettable, ResettableIterator};
+    /// 
+    /// let mut iter = (10..20).resettable();
+    /// iter.next();
+    /// 
+    /// iter.reset_to(3);
+    /// assert_eq!(iter.next(), Some(13));
+    /// ```
+    fn reset_to(&mut self, n: usize) {
+        self.reset();
+
+        if n > 0 {
+            self.nth(n - 1);
+        }
+    }
+
     /// Creates a new iterator from the current one and reset it
     fn reset_clone(&self) -> Self
     where
@@ -26,16 +48,441 @@ pub trait ResettableIterator: Iterator {
             callback,
         }
     }
+
+    /// Creates a ResettableMapWith from the current iterator. The callback gets a mutable state which is set back to `initial_state` on reset, so each pass yields the same items
+    /// 
+    /// # Example
+    /// ```
+    /// use iterators_collection::{IntoResettable, ResettableIterator};
+    /// 
+    /// // Running sums
+    /// let mut iter = (1..5).resettable().resettable_map_with(0, |sum, x| {
+    ///     *sum += x;
+    ///     *sum
+    /// });
+    /// 
+    /// assert_eq!(iter.by_ref().collect::<Vec<i32>>(), vec![1, 3, 6, 10]);
+    /// 
+    /// iter.reset();
+    /// assert_eq!(iter.collect::<Vec<i32>>(), vec![1, 3, 6, 10]);
+    /// ```
+    fn resettable_map_with<S, F, R>(self, initial_state: S, callback: F) -> self::ResettableMapWith<Self, S, F>
+    where
+        S: Clone,
+        F: FnMut(&mut S, Self::Item) -> R,
+        Self: Sized,
+    {
+        ResettableMapWith {
+            iterator: self,
+            state: initial_state.clone(),
+            initial_state,
+            callback,
+        }
+    }
+
+    /// Creates an iterator repeating the current one endlessly, calling `reset` each time it is exhausted. It is the equivalent of `Iterator::cycle` for iterators that can't be cloned
+    /// 
+    /// The first pass starts from the current position. If a pass after a reset yields no item, the iteration stops instead of spinning forever
+    /// 
+    /// # Example
+    /// ```
+    /// use iterators_collection::ResettableIterator;
+    /// use iterators_collection::share::DoubleIterator;
+    /// 
+    /// let mut array = [1, 2];
+    /// let iter = DoubleIterator::new(&mut array).cycle_reset();
+    /// 
+    /// let pairs: Vec<(i32, i32)> = iter.take(5).map(|(i, j)| unsafe { (*i, *j) }).collect();
+    /// assert_eq!(pairs, vec![(1, 2), (2, 1), (1, 2), (2, 1), (1, 2)]);
+    /// ```
+    fn cycle_reset(self) -> self::CycleReset<Self>
+    where
+        Self: Sized,
+    {
+        CycleReset {
+            iterator: self,
+            passes: None,
+            passes_left: None,
+            yielded: true,
+        }
+    }
+
+    /// Creates an iterator repeating the current one `n` times, calling `reset` between the passes. The first pass starts from the current position
+    /// 
+    /// # Example
+    /// ```
+    /// use iterators_collection::{IntoResettable, ResettableIterator};
+    /// 
+    /// let iter = (1..4).resettable().cycle_n(2);
+    /// assert_eq!(iter.collect::<Vec<i32>>(), vec![1, 2, 3, 1, 2, 3]);
+    /// ```
+    fn cycle_n(self, n: usize) -> self::CycleReset<Self>
+    where
+        Self: Sized,
+    {
+        CycleReset {
+            iterator: self,
+            passes: Some(n),
+            passes_left: Some(n),
+            yielded: true,
+        }
+    }
+
+    /// Creates a ResettableFilter from the current iterator
+    fn resettable_filter<P>(self, predicate: P) -> self::ResettableFilter<Self, P>
+    where
+        P: FnMut(&Self::Item) -> bool,
+        Self: Sized,
+    {
+        ResettableFilter {
+            iterator: self,
+            predicate,
+        }
+    }
+
+    /// Creates a ResettableFilterMap from the current iterator
+    fn resettable_filter_map<F, R>(self, callback: F) -> self::ResettableFilterMap<Self, F>
+    where
+        F: FnMut(Self::Item) -> Option<R>,
+        Self: Sized,
+    {
+        ResettableFilterMap {
+            iterator: self,
+            callback,
+        }
+    }
+
+    /// Creates a ResettableTake from the current iterator
+    fn resettable_take(self, n: usize) -> self::ResettableTake<Self>
+    where
+        Self: Sized,
+    {
+        ResettableTake {
+            iterator: self,
+            n,
+            remaining: n,
+        }
+    }
+
+    /// Creates a ResettableSkip from the current iterator
+    fn resettable_skip(self, n: usize) -> self::ResettableSkip<Self>
+    where
+        Self: Sized,
+    {
+        ResettableSkip {
+            iterator: self,
+            n,
+            skipped: false,
+        }
+    }
+
+    /// Creates a ResettableStepBy from the current iterator
+    /// 
+    /// # Panics
+    /// Panics if `step == 0`
+    fn resettable_step_by(self, step: usize) -> self::ResettableStepBy<Self>
+    where
+        Self: Sized,
+    {
+        assert_ne!(step, 0);
+
+        ResettableStepBy {
+            iterator: self,
+            step: step - 1,
+            first_take: true,
+        }
+    }
+
+    /// Creates a ResettableEnumerate from the current iterator
+    fn resettable_enumerate(self) -> self::ResettableEnumerate<Self>
+    where
+        Self: Sized,
+    {
+        ResettableEnumerate {
+            iterator: self,
+            count: 0,
+        }
+    }
+
+    /// Creates a ResettableZip from the current iterator and another resettable one
+    fn resettable_zip<U>(self, other: U) -> self::ResettableZip<Self, U>
+    where
+        U: ResettableIterator,
+        Self: Sized,
+    {
+        ResettableZip {
+            iterator: self,
+            other,
+        }
+    }
+
+    /// Creates a ResettableChain from the current iterator and another resettable one
+    fn resettable_chain<U>(self, other: U) -> self::ResettableChain<Self, U>
+    where
+        U: ResettableIterator<Item = Self::Item>,
+        Self: Sized,
+    {
+        ResettableChain {
+            iterator: self,
+            other,
+            first_done: false,
+        }
+    }
+
+    /// Creates a ResettableTakeWhile from the current iterator
+    fn resettable_take_while<P>(self, predicate: P) -> self::ResettableTakeWhile<Self, P>
+    where
+        P: FnMut(&Self::Item) -> bool,
+        Self: Sized,
+    {
+        ResettableTakeWhile {
+            iterator: self,
+            predicate,
+            done: false,
+        }
+    }
+
+    /// Creates a ResettableSkipWhile from the current iterator
+    fn resettable_skip_while<P>(self, predicate: P) -> self::ResettableSkipWhile<Self, P>
+    where
+        P: FnMut(&Self::Item) -> bool,
+        Self: Sized,
+    {
+        ResettableSkipWhile {
+            iterator: self,
+            predicate,
+            skipped: false,
+        }
+    }
+
+    /// Creates a ResettableInspect from the current iterator
+    fn resettable_inspect<F>(self, callback: F) -> self::ResettableInspect<Self, F>
+    where
+        F: FnMut(&Self::Item),
+        Self: Sized,
+    {
+        ResettableInspect {
+            iterator: self,
+            callback,
+        }
+    }
+
+    /// Creates a ResettableFlatMap from the current iterator
+    fn resettable_flat_map<U, F>(self, callback: F) -> self::ResettableFlatMap<Self, U, F>
+    where
+        U: IntoIterator,
+        F: FnMut(Self::Item) -> U,
+        Self: Sized,
+    {
+        ResettableFlatMap {
+            iterator: self,
+            callback,
+            front: None,
+        }
+    }
+
+    /// Creates a ResettableScan from the current iterator. The state is set back to `initial_state` on reset
+    fn resettable_scan<S, F, R>(self, initial_state: S, callback: F) -> self::ResettableScan<Self, S, F>
+    where
+        S: Clone,
+        F: FnMut(&mut S, Self::Item) -> Option<R>,
+        Self: Sized,
+    {
+        ResettableScan {
+            iterator: self,
+            state: initial_state.clone(),
+            initial_state,
+            callback,
+        }
+    }
+}
+
+/// Implements `ResettableIterator` for an adapter by resetting one of its fields, which is enough for the adapters without any state of their own
+/// 
+/// The syntax is `impl_resettable_iterator!([generics] Type, field: FieldType)`, optionally followed by `, where` and some bounds. The field type is required to implement `ResettableIterator`
+/// 
+/// # Example
+/// ```
+/// use iterators_collection::{impl_resettable_iterator, IntoResettable, ResettableIterator};
+/// 
+/// struct Doubled<I> {
+///     inner: I,
+/// }
+/// 
+/// impl<I> Iterator for Doubled<I>
+/// where
+///     I: Iterator<Item = u32>,
+/// {
+///     type Item = u32;
+/// 
+///     fn next(&mut self) -> Option<u32> {
+///         self.inner.next().map(|x| x * 2)
+///     }
+/// }
+/// 
+/// impl_resettable_iterator!([I] Doubled<I>, inner: I, where I: Iterator<Item = u32>);
+/// 
+/// let mut iter = Doubled { inner: (0..3).resettable() };
+/// iter.next();
+/// iter.reset();
+/// assert_eq!(iter.next(), Some(0));
+/// ```
+#[macro_export]
+macro_rules! impl_resettable_iterator {
+    ([$($generics:tt)*] $type:ty, $field:ident: $inner:ty $(, where $($bounds:tt)*)?) => {
+        impl<$($generics)*> $crate::ResettableIterator for $type
+        where
+            $inner: $crate::ResettableIterator,
+            $($($bounds)*)?
+        {
+            fn reset(&mut self) {
+                $crate::ResettableIterator::reset(&mut self.$field);
+            }
+        }
+    };
+}
+
+/// An iterator able to save its position and to go back to it later. It is more general than `ResettableIterator` which can only go back to the first position
+/// 
+/// # Example
+/// ```
+/// use iterators_collection::{CheckpointIterator, IntoResettable};
+/// 
+/// let mut iter = "let x = 1;".chars().resettable();
+/// iter.next();
+/// 
+/// let mark = iter.checkpoint();
+/// assert_eq!(iter.next(), Some('e'));
+/// assert_eq!(iter.next(), Some('t'));
+/// 
+/// iter.restore(mark);
+/// assert_eq!(iter.next(), Some('e'));
+/// ```
+pub trait CheckpointIterator: Iterator {
+    /// The type of a saved position
+    type Mark;
+
+    /// Returns the current position of the iterator
+    fn checkpoint(&self) -> Self::Mark;
+
+    /// Sets the iterator back to a position returned by its `checkpoint` method
+    fn restore(&mut self, mark: Self::Mark);
+}
+
+/// An iterator able to walk backwards from its current position. Unlike `DoubleEndedIterator` which yields items from the end, `prev` moves the position of the iterator back
+/// 
+/// The position is between two items: `next` yields the item after it and moves forward, `prev` yields the item before it and moves backward. So calling `prev` right after `next` yields the same item again
+/// 
+/// # Example
+/// ```
+/// use iterators_collection::{BidirectionalIterator, SliceCursor};
+/// 
+/// let array = [1, 2, 3];
+/// let mut iter = SliceCursor::new(&array);
+/// 
+/// assert_eq!(iter.next(), Some(&1));
+/// assert_eq!(iter.next(), Some(&2));
+/// assert_eq!(iter.prev(), Some(&2));
+/// assert_eq!(iter.prev(), Some(&1));
+/// assert_eq!(iter.prev(), None);
+/// ```
+pub trait BidirectionalIterator: Iterator {
+    /// Moves the iterator one item backwards and returns this item, or returns `None` if it is already at its first position
+    fn prev(&mut self) -> Option<Self::Item>;
+}
+
+/// A cursor over a slice which can go forwards with `next` and backwards with `prev`
+#[derive(Clone, Debug)]
+pub struct SliceCursor<'a, T> {
+    slice: &'a [T],
+    position: usize,
+}
+
+impl<'a, T> SliceCursor<'a, T> {
+    /// Returns a new cursor before the first element of `slice`
+    pub fn new(slice: &'a [T]) -> Self {
+        Self {
+            slice,
+            position: 0,
+        }
+    }
+
+    /// Returns the number of items yielded by `next` since the cursor was at the first position
+    pub fn position(&self) -> usize {
+        self.position
+    }
+}
+
+impl<'a, T> Iterator for SliceCursor<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        let item = self.slice.get(self.position)?;
+        self.position += 1;
+
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.slice.len() - self.position;
+        (remaining, Some(remaining))
+    }
+}
+
+crate::impl_root_iterator!(['a, T] SliceCursor<'a, T>);
+
+impl<T> ExactSizeIterator for SliceCursor<'_, T> {}
+
+impl<T> self::BidirectionalIterator for SliceCursor<'_, T> {
+    fn prev(&mut self) -> Option<Self::Item> {
+        if self.position == 0 {
+            return None;
+        }
+
+        self.position -= 1;
+        Some(&self.slice[self.position])
+    }
+}
+
+impl<T> self::ResettableIterator for SliceCursor<'_, T> {
+    fn reset(&mut self) {
+        self.position = 0;
+    }
+}
+
+impl<T> self::CheckpointIterator for SliceCursor<'_, T> {
+    type Mark = usize;
+
+    fn checkpoint(&self) -> usize {
+        self.position
+    }
+
+    fn restore(&mut self, mark: usize) {
+        assert!(mark <= self.slice.len());
+        self.position = mark;
+    }
 }
 
 /// A resettable version of `std::iter::Map`. A simple trait implementation is not suitable because it requires to get access to private elements of `std::iter::Map` like the iterator stored
 /// 
 /// You can use it like you would use `std::iter::Map` but it implements the `ResettableIterator` trait too
+#[derive(Clone)]
 pub struct ResettableMap<I, F> {
     iterator: I,
     callback: F,
 }
 
+impl<I, F> std::fmt::Debug for ResettableMap<I, F>
+where
+    I: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ResettableMap")
+            .field("iterator", &self.iterator)
+            .finish()
+    }
+}
+
 impl<I, F, R> Iterator for ResettableMap<I, F>
 where
     I: Iterator,
@@ -49,38 +496,2060 @@ where
 
         Some(result)
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iterator.size_hint()
+    }
+
+    fn fold<B, G>(self, init: B, mut g: G) -> B
+    where
+        G: FnMut(B, R) -> B,
+    {
+        let mut callback = self.callback;
+        self.iterator.fold(init, move |acc, item| g(acc, callback(item)))
+    }
 }
 
-impl<I, F, R> self::ResettableIterator for ResettableMap<I, F>
+impl<I, F, R> DoubleEndedIterator for ResettableMap<I, F>
 where
-    I: self::ResettableIterator,
+    I: DoubleEndedIterator,
     F: FnMut(I::Item) -> R,
 {
-    fn reset(&mut self) {
-        self.iterator.reset();
+    fn next_back(&mut self) -> Option<R> {
+        let item = self.iterator.next_back()?;
+        let result = (self.callback)(item);
+
+        Some(result)
+    }
+
+    fn rfold<B, G>(self, init: B, mut g: G) -> B
+    where
+        G: FnMut(B, R) -> B,
+    {
+        let mut callback = self.callback;
+        self.iterator.rfold(init, move |acc, item| g(acc, callback(item)))
+    }
+}
+
+impl<I, F, R> ExactSizeIterator for ResettableMap<I, F>
+where
+    I: ExactSizeIterator,
+    F: FnMut(I::Item) -> R,
+{
+    fn len(&self) -> usize {
+        self.iterator.len()
+    }
+}
+
+impl<I, F, R> std::iter::FusedIterator for ResettableMap<I, F>
+where
+    I: std::iter::FusedIterator,
+    F: FnMut(I::Item) -> R,
+{
+}
+
+crate::impl_resettable_iterator!([I, F, R] ResettableMap<I, F>, iterator: I, where F: FnMut(I::Item) -> R);
+
+impl<I, F, R> self::BidirectionalIterator for ResettableMap<I, F>
+where
+    I: self::BidirectionalIterator,
+    F: FnMut(I::Item) -> R,
+{
+    fn prev(&mut self) -> Option<R> {
+        let item = self.iterator.prev()?;
+        let result = (self.callback)(item);
+
+        Some(result)
+    }
+}
+
+impl<I, F, R> self::CheckpointIterator for ResettableMap<I, F>
+where
+    I: self::CheckpointIterator,
+    F: FnMut(I::Item) -> R,
+{
+    type Mark = I::Mark;
+
+    fn checkpoint(&self) -> I::Mark {
+        self.iterator.checkpoint()
+    }
+
+    fn restore(&mut self, mark: I::Mark) {
+        self.iterator.restore(mark);
     }
 }
 
-impl<I, F, R> crate::child::ChildIterator for ResettableMap<I, F>
+crate::impl_child_iterator!([I, F, R] ResettableMap<I, F>, iterator: I, where F: FnMut(I::Item) -> R);
+crate::impl_describe!([I, F] ResettableMap<I, F>, iterator: I);
+
+impl<I, F, R, P> crate::child::WithParent<P> for ResettableMap<I, F>
 where
     I: Iterator,
     F: FnMut(I::Item) -> R,
+    P: Iterator<Item = I::Item>,
 {
-    type Parent = I;
+    type Output = ResettableMap<P, F>;
 
-    fn release_parent(self) -> I {
-        self.iterator
+    fn with_parent(self, parent: P) -> ResettableMap<P, F> {
+        ResettableMap {
+            iterator: parent,
+            callback: self.callback,
+        }
     }
+}
+
+/// A `ResettableMap` whose callback gets a mutable state, set back to its initial value on reset
+/// 
+/// A `ResettableMap` callback capturing a mutable variable keeps it across the resets, so the passes may yield different items. This adapter stores the state itself to avoid it
+#[derive(Clone)]
+pub struct ResettableMapWith<I, S, F> {
+    iterator: I,
+    initial_state: S,
+    state: S,
+    callback: F,
+}
+
+impl<I, S, F, R> Iterator for ResettableMapWith<I, S, F>
+where
+    I: Iterator,
+    F: FnMut(&mut S, I::Item) -> R,
+{
+    type Item = R;
+
+    fn next(&mut self) -> Option<R> {
+        let item = self.iterator.next()?;
+        let result = (self.callback)(&mut self.state, item);
 
-    fn get_parent_mut(&mut self) -> &mut I {
-        &mut self.iterator
+        Some(result)
     }
 
-    fn get_parent(&self) -> &I {
-        &self.iterator
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iterator.size_hint()
     }
 }
 
+impl<I, S, F, R> ExactSizeIterator for ResettableMapWith<I, S, F>
+where
+    I: ExactSizeIterator,
+    F: FnMut(&mut S, I::Item) -> R,
+{
+}
+
+impl<I, S, F, R> self::ResettableIterator for ResettableMapWith<I, S, F>
+where
+    I: self::ResettableIterator,
+    S: Clone,
+    F: FnMut(&mut S, I::Item) -> R,
+{
+    fn reset(&mut self) {
+        self.iterator.reset();
+        self.state = self.initial_state.clone();
+    }
+}
+
+crate::impl_child_iterator!([I, S, F, R] ResettableMapWith<I, S, F>, iterator: I, where F: FnMut(&mut S, I::Item) -> R);
+crate::impl_describe!([I, S, F] ResettableMapWith<I, S, F>, iterator: I);
+
+/// A resettable version of `std::iter::Filter`
+#[derive(Clone)]
+pub struct ResettableFilter<I, P> {
+    iterator: I,
+    predicate: P,
+}
+
+impl<I, P> Iterator for ResettableFilter<I, P>
+where
+    I: Iterator,
+    P: FnMut(&I::Item) -> bool,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        loop {
+            let item = self.iterator.next()?;
+            if (self.predicate)(&item) {
+                return Some(item);
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iterator.size_hint().1)
+    }
+}
+
+crate::impl_resettable_iterator!([I, P] ResettableFilter<I, P>, iterator: I, where P: FnMut(&I::Item) -> bool);
+
+crate::impl_child_iterator!([I, P] ResettableFilter<I, P>, iterator: I, where P: FnMut(&I::Item) -> bool);
+crate::impl_describe!([I, P] ResettableFilter<I, P>, iterator: I);
+
+/// A resettable version of `std::iter::FilterMap`
+#[derive(Clone)]
+pub struct ResettableFilterMap<I, F> {
+    iterator: I,
+    callback: F,
+}
+
+impl<I, F, R> Iterator for ResettableFilterMap<I, F>
+where
+    I: Iterator,
+    F: FnMut(I::Item) -> Option<R>,
+{
+    type Item = R;
+
+    fn next(&mut self) -> Option<R> {
+        loop {
+            let item = self.iterator.next()?;
+            if let Some(result) = (self.callback)(item) {
+                return Some(result);
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iterator.size_hint().1)
+    }
+}
+
+crate::impl_resettable_iterator!([I, F, R] ResettableFilterMap<I, F>, iterator: I, where F: FnMut(I::Item) -> Option<R>);
+
+crate::impl_child_iterator!([I, F, R] ResettableFilterMap<I, F>, iterator: I, where F: FnMut(I::Item) -> Option<R>);
+crate::impl_describe!([I, F] ResettableFilterMap<I, F>, iterator: I);
+
+/// A resettable version of `std::iter::Take`
+#[derive(Clone)]
+pub struct ResettableTake<I> {
+    iterator: I,
+    n: usize,
+    remaining: usize,
+}
+
+impl<I> Iterator for ResettableTake<I>
+where
+    I: Iterator,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        self.remaining -= 1;
+        self.iterator.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iterator.size_hint();
+        let upper = match upper {
+            Some(upper) => upper.min(self.remaining),
+            None        => self.remaining,
+        };
+
+        (lower.min(self.remaining), Some(upper))
+    }
+}
+
+impl<I> self::ResettableIterator for ResettableTake<I>
+where
+    I: self::ResettableIterator,
+{
+    fn reset(&mut self) {
+        self.iterator.reset();
+        self.remaining = self.n;
+    }
+}
+
+crate::impl_child_iterator!([I] ResettableTake<I>, iterator: I);
+crate::impl_describe!([I] ResettableTake<I>, iterator: I);
+
+/// A resettable version of `std::iter::Skip`
+#[derive(Clone)]
+pub struct ResettableSkip<I> {
+    iterator: I,
+    n: usize,
+    skipped: bool,
+}
+
+impl<I> Iterator for ResettableSkip<I>
+where
+    I: Iterator,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        if self.skipped {
+            self.iterator.next()
+        } else {
+            self.skipped = true;
+            self.iterator.nth(self.n)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iterator.size_hint();
+        if self.skipped {
+            (lower, upper)
+        } else {
+            (lower.saturating_sub(self.n), upper.map(|upper| upper.saturating_sub(self.n)))
+        }
+    }
+}
+
+impl<I> self::ResettableIterator for ResettableSkip<I>
+where
+    I: self::ResettableIterator,
+{
+    fn reset(&mut self) {
+        self.iterator.reset();
+        self.skipped = false;
+    }
+}
+
+crate::impl_child_iterator!([I] ResettableSkip<I>, iterator: I);
+crate::impl_describe!([I] ResettableSkip<I>, iterator: I);
+
+/// A resettable version of `std::iter::StepBy`
+#[derive(Clone)]
+pub struct ResettableStepBy<I> {
+    iterator: I,
+    step: usize,
+    first_take: bool,
+}
+
+impl<I> Iterator for ResettableStepBy<I>
+where
+    I: Iterator,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        if self.first_take {
+            self.first_take = false;
+            self.iterator.next()
+        } else {
+            self.iterator.nth(self.step)
+        }
+    }
+}
+
+impl<I> self::ResettableIterator for ResettableStepBy<I>
+where
+    I: self::ResettableIterator,
+{
+    fn reset(&mut self) {
+        self.iterator.reset();
+        self.first_take = true;
+    }
+}
+
+crate::impl_child_iterator!([I] ResettableStepBy<I>, iterator: I);
+crate::impl_describe!([I] ResettableStepBy<I>, iterator: I);
+
+/// A resettable version of `std::iter::Enumerate`
+#[derive(Clone)]
+pub struct ResettableEnumerate<I> {
+    iterator: I,
+    count: usize,
+}
+
+impl<I> Iterator for ResettableEnumerate<I>
+where
+    I: Iterator,
+{
+    type Item = (usize, I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iterator.next()?;
+        self.count += 1;
+
+        Some((self.count - 1, item))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iterator.size_hint()
+    }
+}
+
+impl<I> self::ResettableIterator for ResettableEnumerate<I>
+where
+    I: self::ResettableIterator,
+{
+    fn reset(&mut self) {
+        self.iterator.reset();
+        self.count = 0;
+    }
+}
+
+crate::impl_child_iterator!([I] ResettableEnumerate<I>, iterator: I);
+crate::impl_describe!([I] ResettableEnumerate<I>, iterator: I);
+
+/// A resettable version of `std::iter::Zip`. Both iterators are reset together
+/// 
+/// Its parent as a `ChildIterator` is the first iterator, the one `resettable_zip` has been called on
+#[derive(Clone)]
+pub struct ResettableZip<A, B> {
+    iterator: A,
+    other: B,
+}
+
+impl<A, B> Iterator for ResettableZip<A, B>
+where
+    A: Iterator,
+    B: Iterator,
+{
+    type Item = (A::Item, B::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let a = self.iterator.next()?;
+        let b = self.other.next()?;
+
+        Some((a, b))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (a_lower, a_upper) = self.iterator.size_hint();
+        let (b_lower, b_upper) = self.other.size_hint();
+
+        let upper = match (a_upper, b_upper) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (Some(a), None)    => Some(a),
+            (None, Some(b))    => Some(b),
+            (None, None)       => None,
+        };
+
+        (a_lower.min(b_lower), upper)
+    }
+}
+
+impl<A, B> self::ResettableIterator for ResettableZip<A, B>
+where
+    A: self::ResettableIterator,
+    B: self::ResettableIterator,
+{
+    fn reset(&mut self) {
+        self.iterator.reset();
+        self.other.reset();
+    }
+}
+
+crate::impl_child_iterator!([A, B] ResettableZip<A, B>, iterator: A, where B: Iterator);
+crate::impl_describe!([A, B] ResettableZip<A, B>, iterator: A);
+
+/// A resettable version of `std::iter::Chain`. Both iterators are reset together
+/// 
+/// Its parent as a `ChildIterator` is the first iterator, the one `resettable_chain` has been called on
+#[derive(Clone)]
+pub struct ResettableChain<A, B> {
+    iterator: A,
+    other: B,
+    first_done: bool,
+}
+
+impl<A, B> Iterator for ResettableChain<A, B>
+where
+    A: Iterator,
+    B: Iterator<Item = A::Item>,
+{
+    type Item = A::Item;
+
+    fn next(&mut self) -> Option<A::Item> {
+        if !self.first_done {
+            match self.iterator.next() {
+                None       => self.first_done = true,
+                Some(item) => return Some(item),
+            }
+        }
+
+        self.other.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (b_lower, b_upper) = self.other.size_hint();
+        if self.first_done {
+            return (b_lower, b_upper);
+        }
+
+        let (a_lower, a_upper) = self.iterator.size_hint();
+        let upper = match (a_upper, b_upper) {
+            (Some(a), Some(b)) => a.checked_add(b),
+            _                  => None,
+        };
+
+        (a_lower.saturating_add(b_lower), upper)
+    }
+}
+
+impl<A, B> self::ResettableIterator for ResettableChain<A, B>
+where
+    A: self::ResettableIterator,
+    B: self::ResettableIterator<Item = A::Item>,
+{
+    fn reset(&mut self) {
+        self.iterator.reset();
+        self.other.reset();
+        self.first_done = false;
+    }
+}
+
+crate::impl_child_iterator!([A, B] ResettableChain<A, B>, iterator: A, where B: Iterator<Item = A::Item>);
+crate::impl_describe!([A, B] ResettableChain<A, B>, iterator: A);
+
+/// A resettable version of `std::iter::TakeWhile`
+#[derive(Clone)]
+pub struct ResettableTakeWhile<I, P> {
+    iterator: I,
+    predicate: P,
+    done: bool,
+}
+
+impl<I, P> Iterator for ResettableTakeWhile<I, P>
+where
+    I: Iterator,
+    P: FnMut(&I::Item) -> bool,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        if self.done {
+            return None;
+        }
+
+        let item = self.iterator.next()?;
+        if (self.predicate)(&item) {
+            Some(item)
+        } else {
+            self.done = true;
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            (0, self.iterator.size_hint().1)
+        }
+    }
+}
+
+impl<I, P> self::ResettableIterator for ResettableTakeWhile<I, P>
+where
+    I: self::ResettableIterator,
+    P: FnMut(&I::Item) -> bool,
+{
+    fn reset(&mut self) {
+        self.iterator.reset();
+        self.done = false;
+    }
+}
+
+crate::impl_child_iterator!([I, P] ResettableTakeWhile<I, P>, iterator: I, where P: FnMut(&I::Item) -> bool);
+crate::impl_describe!([I, P] ResettableTakeWhile<I, P>, iterator: I);
+
+/// A resettable version of `std::iter::SkipWhile`
+#[derive(Clone)]
+pub struct ResettableSkipWhile<I, P> {
+    iterator: I,
+    predicate: P,
+    skipped: bool,
+}
+
+impl<I, P> Iterator for ResettableSkipWhile<I, P>
+where
+    I: Iterator,
+    P: FnMut(&I::Item) -> bool,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        if self.skipped {
+            return self.iterator.next();
+        }
+
+        self.skipped = true;
+        loop {
+            let item = self.iterator.next()?;
+            if !(self.predicate)(&item) {
+                return Some(item);
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.skipped {
+            self.iterator.size_hint()
+        } else {
+            (0, self.iterator.size_hint().1)
+        }
+    }
+}
+
+impl<I, P> self::ResettableIterator for ResettableSkipWhile<I, P>
+where
+    I: self::ResettableIterator,
+    P: FnMut(&I::Item) -> bool,
+{
+    fn reset(&mut self) {
+        self.iterator.reset();
+        self.skipped = false;
+    }
+}
+
+crate::impl_child_iterator!([I, P] ResettableSkipWhile<I, P>, iterator: I, where P: FnMut(&I::Item) -> bool);
+crate::impl_describe!([I, P] ResettableSkipWhile<I, P>, iterator: I);
+
+/// A resettable version of `std::iter::Inspect`
+#[derive(Clone)]
+pub struct ResettableInspect<I, F> {
+    iterator: I,
+    callback: F,
+}
+
+impl<I, F> Iterator for ResettableInspect<I, F>
+where
+    I: Iterator,
+    F: FnMut(&I::Item),
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        let item = self.iterator.next()?;
+        (self.callback)(&item);
+
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iterator.size_hint()
+    }
+}
+
+crate::impl_resettable_iterator!([I, F] ResettableInspect<I, F>, iterator: I, where F: FnMut(&I::Item));
+
+crate::impl_child_iterator!([I, F] ResettableInspect<I, F>, iterator: I, where F: FnMut(&I::Item));
+crate::impl_describe!([I, F] ResettableInspect<I, F>, iterator: I);
+
+/// A resettable version of `std::iter::FlatMap`. The inner iterators are not reset but created again by the callback
+pub struct ResettableFlatMap<I, U, F>
+where
+    U: IntoIterator,
+{
+    iterator: I,
+    callback: F,
+    front: Option<U::IntoIter>,
+}
+
+impl<I, U, F> Iterator for ResettableFlatMap<I, U, F>
+where
+    I: Iterator,
+    U: IntoIterator,
+    F: FnMut(I::Item) -> U,
+{
+    type Item = U::Item;
+
+    fn next(&mut self) -> Option<U::Item> {
+        loop {
+            if let Some(inner) = &mut self.front {
+                if let Some(item) = inner.next() {
+                    return Some(item);
+                }
+            }
+
+            let item = self.iterator.next()?;
+            self.front = Some((self.callback)(item).into_iter());
+        }
+    }
+}
+
+impl<I, U, F> self::ResettableIterator for ResettableFlatMap<I, U, F>
+where
+    I: self::ResettableIterator,
+    U: IntoIterator,
+    F: FnMut(I::Item) -> U,
+{
+    fn reset(&mut self) {
+        self.iterator.reset();
+        self.front = None;
+    }
+}
+
+crate::impl_child_iterator!([I, U, F] ResettableFlatMap<I, U, F>, iterator: I, where U: IntoIterator, F: FnMut(I::Item) -> U);
+crate::impl_describe!([I, U, F] ResettableFlatMap<I, U, F>, iterator: I, where U: IntoIterator);
+
+/// A resettable version of `std::iter::Scan`. The state is set back to its initial value on reset
+#[derive(Clone)]
+pub struct ResettableScan<I, S, F> {
+    iterator: I,
+    initial_state: S,
+    state: S,
+    callback: F,
+}
+
+impl<I, S, F, R> Iterator for ResettableScan<I, S, F>
+where
+    I: Iterator,
+    F: FnMut(&mut S, I::Item) -> Option<R>,
+{
+    type Item = R;
+
+    fn next(&mut self) -> Option<R> {
+        let item = self.iterator.next()?;
+        (self.callback)(&mut self.state, item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iterator.size_hint().1)
+    }
+}
+
+impl<I, S, F, R> self::ResettableIterator for ResettableScan<I, S, F>
+where
+    I: self::ResettableIterator,
+    S: Clone,
+    F: FnMut(&mut S, I::Item) -> Option<R>,
+{
+    fn reset(&mut self) {
+        self.iterator.reset();
+        self.state = self.initial_state.clone();
+    }
+}
+
+crate::impl_child_iterator!([I, S, F, R] ResettableScan<I, S, F>, iterator: I, where F: FnMut(&mut S, I::Item) -> Option<R>);
+crate::impl_describe!([I, S, F] ResettableScan<I, S, F>, iterator: I);
+
+/// Repeats a resettable iterator, endlessly or a given number of times. Created by the `cycle_reset` and `cycle_n` methods of `ResettableIterator`
+#[derive(Clone)]
+pub struct CycleReset<I> {
+    iterator: I,
+    passes: Option<usize>,
+    passes_left: Option<usize>,
+    yielded: bool,
+}
+
+impl<I> Iterator for CycleReset<I>
+where
+    I: self::ResettableIterator,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        loop {
+            if self.passes_left == Some(0) {
+                return None;
+            }
+
+            if let Some(item) = self.iterator.next() {
+                self.yielded = true;
+                return Some(item);
+            }
+
+            // End of a pass
+            if let Some(left) = &mut self.passes_left {
+                *left -= 1;
+            }
+
+            // An empty pass would lead to an infinite loop
+            if !self.yielded {
+                self.passes_left = Some(0);
+                return None;
+            }
+
+            if self.passes_left != Some(0) {
+                self.iterator.reset();
+                self.yielded = false;
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match (self.passes_left, self.iterator.size_hint()) {
+            (Some(0), _)          => (0, Some(0)),
+            (Some(_), (lower, _)) => (lower, None),
+            (None, (0, _))        => (0, None),
+            (None, _)             => (usize::MAX, None),
+        }
+    }
+}
+
+impl<I> self::ResettableIterator for CycleReset<I>
+where
+    I: self::ResettableIterator,
+{
+    fn reset(&mut self) {
+        self.iterator.reset();
+        self.passes_left = self.passes;
+        self.yielded = true;
+    }
+}
+
+crate::impl_child_iterator!([I] CycleReset<I>, iterator: I, where I: self::ResettableIterator);
+crate::impl_describe!([I] CycleReset<I>, iterator: I);
+
+/// Creates a resettable iterator from a state and a closure. Each call to `next` calls `callback` with a mutable reference to the state, and the state is set back to `init` on reset
+/// 
+/// It is a resettable version of `std::iter::from_fn` where the state is stored outside the closure
+/// 
+/// # Example
+/// ```
+/// use iterators_collection::{from_fn_resettable, ResettableIterator};
+/// 
+/// let mut iter = from_fn_resettable((0, 1), |(a, b): &mut (u32, u32)| {
+///     let current = *a;
+///     *a = *b;
+///     *b += current;
+/// 
+///     Some(current)
+/// });
+/// 
+/// assert_eq!(iter.by_ref().take(6).collect::<Vec<u32>>(), vec![0, 1, 1, 2, 3, 5]);
+/// 
+/// iter.reset();
+/// assert_eq!(iter.next(), Some(0));
+/// ```
+pub fn from_fn_resettable<S, F, T>(init: S, callback: F) -> ResettableFromFn<S, F>
+where
+    S: Clone,
+    F: FnMut(&mut S) -> Option<T>,
+{
+    ResettableFromFn {
+        state: init.clone(),
+        init,
+        callback,
+    }
+}
+
+/// The iterator returned by `from_fn_resettable`
+#[derive(Clone)]
+pub struct ResettableFromFn<S, F> {
+    init: S,
+    state: S,
+    callback: F,
+}
+
+impl<S, F, T> Iterator for ResettableFromFn<S, F>
+where
+    F: FnMut(&mut S) -> Option<T>,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        (self.callback)(&mut self.state)
+    }
+}
+
+crate::impl_root_iterator!([S, F] ResettableFromFn<S, F>);
+
+impl<S, F, T> self::ResettableIterator for ResettableFromFn<S, F>
+where
+    S: Clone,
+    F: FnMut(&mut S) -> Option<T>,
+{
+    fn reset(&mut self) {
+        self.state = self.init.clone();
+    }
+}
+
+impl<S, F, T> self::CheckpointIterator for ResettableFromFn<S, F>
+where
+    S: Clone,
+    F: FnMut(&mut S) -> Option<T>,
+{
+    type Mark = S;
+
+    fn checkpoint(&self) -> S {
+        self.state.clone()
+    }
+
+    fn restore(&mut self, mark: S) {
+        self.state = mark;
+    }
+}
+
+/// Creates a resettable iterator by unfolding a seed. Each call to `next` gives the state to `callback`, which returns the item to yield and the next state, or `None` to stop
+/// 
+/// The state is set back to `seed` on reset
+/// 
+/// # Example
+/// ```
+/// use iterators_collection::{unfold_resettable, ResettableIterator};
+/// 
+/// // The digits of a number, from the lowest
+/// let mut iter = unfold_resettable(1234, |n: u32| if n == 0 { None } else { Some((n % 10, n / 10)) });
+/// 
+/// assert_eq!(iter.by_ref().collect::<Vec<u32>>(), vec![4, 3, 2, 1]);
+/// 
+/// iter.reset();
+/// assert_eq!(iter.next(), Some(4));
+/// ```
+pub fn unfold_resettable<S, F, T>(seed: S, callback: F) -> ResettableUnfold<S, F>
+where
+    S: Clone,
+    F: FnMut(S) -> Option<(T, S)>,
+{
+    ResettableUnfold {
+        state: Some(seed.clone()),
+        seed,
+        callback,
+    }
+}
+
+/// The iterator returned by `unfold_resettable`
+#[derive(Clone)]
+pub struct ResettableUnfold<S, F> {
+    seed: S,
+    state: Option<S>,
+    callback: F,
+}
+
+impl<S, F, T> Iterator for ResettableUnfold<S, F>
+where
+    F: FnMut(S) -> Option<(T, S)>,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        let (item, state) = (self.callback)(self.state.take()?)?;
+        self.state = Some(state);
+
+        Some(item)
+    }
+}
+
+crate::impl_root_iterator!([S, F] ResettableUnfold<S, F>);
+
+impl<S, F, T> self::ResettableIterator for ResettableUnfold<S, F>
+where
+    S: Clone,
+    F: FnMut(S) -> Option<(T, S)>,
+{
+    fn reset(&mut self) {
+        self.state = Some(self.seed.clone());
+    }
+}
+
+impl<S, F, T> self::CheckpointIterator for ResettableUnfold<S, F>
+where
+    S: Clone,
+    F: FnMut(S) -> Option<(T, S)>,
+{
+    type Mark = Option<S>;
+
+    fn checkpoint(&self) -> Option<S> {
+        self.state.clone()
+    }
+
+    fn restore(&mut self, mark: Option<S>) {
+        self.state = mark;
+    }
+}
+
+/// Creates a resettable iterator where each item is computed from the previous one. It is a resettable version of `std::iter::successors`
+/// 
+/// # Example
+/// ```
+/// use iterators_collection::{successors_resettable, ResettableIterator};
+/// 
+/// let mut iter = successors_resettable(Some(1u32), |n| n.checked_mul(10));
+/// 
+/// assert_eq!(iter.by_ref().take(3).collect::<Vec<u32>>(), vec![1, 10, 100]);
+/// 
+/// iter.reset();
+/// assert_eq!(iter.count(), 10);
+/// ```
+pub fn successors_resettable<T, F>(first: Option<T>, callback: F) -> ResettableSuccessors<T, F>
+where
+    T: Clone,
+    F: FnMut(&T) -> Option<T>,
+{
+    ResettableSuccessors {
+        next: first.clone(),
+        first,
+        callback,
+    }
+}
+
+/// The iterator returned by `successors_resettable`
+#[derive(Clone)]
+pub struct ResettableSuccessors<T, F> {
+    first: Option<T>,
+    next: Option<T>,
+    callback: F,
+}
+
+impl<T, F> Iterator for ResettableSuccessors<T, F>
+where
+    F: FnMut(&T) -> Option<T>,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        let item = self.next.take()?;
+        self.next = (self.callback)(&item);
+
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.next.is_some() {
+            (1, None)
+        } else {
+            (0, Some(0))
+        }
+    }
+}
+
+crate::impl_root_iterator!([T, F] ResettableSuccessors<T, F>);
+
+impl<T, F> self::ResettableIterator for ResettableSuccessors<T, F>
+where
+    T: Clone,
+    F: FnMut(&T) -> Option<T>,
+{
+    fn reset(&mut self) {
+        self.next = self.first.clone();
+    }
+}
+
+impl<T, F> self::CheckpointIterator for ResettableSuccessors<T, F>
+where
+    T: Clone,
+    F: FnMut(&T) -> Option<T>,
+{
+    type Mark = Option<T>;
+
+    fn checkpoint(&self) -> Option<T> {
+        self.next.clone()
+    }
+
+    fn restore(&mut self, mark: Option<T>) {
+        self.next = mark;
+    }
+}
+
+/// Computes the mean and the variance of the items of a resettable iterator in two passes, without storing them. The first pass computes the mean and the second one the variance from it, which is more accurate than the one-pass formula
+/// 
+/// The variance is the population variance, divided by the number of items. Returns `None` if the iterator is empty
+/// 
+/// The iterator is reset before each pass
+/// 
+/// # Example
+/// ```
+/// use iterators_collection::{two_pass_mean_variance, IntoResettable};
+/// 
+/// let array = [2, 4, 4, 4, 5, 5, 7, 9];
+/// let mut iter = array.iter().cloned().resettable();
+/// 
+/// assert_eq!(two_pass_mean_variance(&mut iter), Some((5.0, 4.0)));
+/// ```
+pub fn two_pass_mean_variance<I>(iterator: &mut I) -> Option<(f64, f64)>
+where
+    I: self::ResettableIterator,
+    I::Item: Into<f64>,
+{
+    iterator.reset();
+    let (count, sum) = iterator
+        .by_ref()
+        .fold((0usize, 0.0), |(count, sum), x| (count + 1, sum + x.into()));
+
+    if count == 0 {
+        return None;
+    }
+    let mean = sum / count as f64;
+
+    iterator.reset();
+    let squares: f64 = iterator.by_ref().map(|x| (x.into() - mean).powi(2)).sum();
+
+    Some((mean, squares / count as f64))
+}
+
+/// Computes the median of the items of a resettable iterator without storing them. For an even number of items, it is the mean of the two middle ones
+/// 
+/// It does a binary search on the values, each step being one pass on the iterator. The number of passes is bounded by the number of distinct values, and is usually logarithmic. Returns `None` if the iterator is empty. NaN values lead to an unspecified result
+/// 
+/// The iterator is reset before each pass
+/// 
+/// # Example
+/// ```
+/// use iterators_collection::{median_of, IntoResettable};
+/// 
+/// let array = [7, 1, 5, 3];
+/// let mut iter = array.iter().cloned().resettable();
+/// 
+/// assert_eq!(median_of(&mut iter), Some(4.0));
+/// ```
+pub fn median_of<I>(iterator: &mut I) -> Option<f64>
+where
+    I: self::ResettableIterator,
+    I::Item: Into<f64>,
+{
+    iterator.reset();
+    let (count, min, max) = iterator.by_ref().fold((0usize, f64::INFINITY, f64::NEG_INFINITY), |(count, min, max), x| {
+        let x = x.into();
+        (count + 1, min.min(x), max.max(x))
+    });
+
+    if count == 0 {
+        return None;
+    }
+
+    let lower = nth_smallest(iterator, (count - 1) / 2, min, max);
+    if count % 2 == 1 {
+        Some(lower)
+    } else {
+        let upper = nth_smallest(iterator, count / 2, min, max);
+        Some((lower + upper) / 2.0)
+    }
+}
+
+/// Returns the `n`th smallest item (starting from 0) of a resettable iterator whose items are all in `min..=max`
+fn nth_smallest<I>(iterator: &mut I, n: usize, mut min: f64, mut max: f64) -> f64
+where
+    I: self::ResettableIterator,
+    I::Item: Into<f64>,
+{
+    // The number of items lower than `min`
+    let mut below = 0;
+
+    while min < max {
+        let mut middle = min + (max - min) / 2.0;
+        if middle >= max {
+            middle = min;
+        }
+
+        // Counts the items in `min..=middle` and finds the values surrounding `middle`
+        iterator.reset();
+        let (count, lower_max, upper_min) = iterator.by_ref().fold((0, f64::NEG_INFINITY, f64::INFINITY), |(count, lower_max, upper_min), x| {
+            let x = x.into();
+            if x < min || x > max {
+                (count, lower_max, upper_min)
+            } else if x <= middle {
+                (count + 1, lower_max.max(x), upper_min)
+            } else {
+                (count, lower_max, upper_min.min(x))
+            }
+        });
+
+        if below + count > n {
+            max = lower_max;
+        } else {
+            below += count;
+            min = upper_min;
+        }
+    }
+
+    min
+}
+
+/// Creates an iterator yielding the items of a resettable iterator scaled to `0.0..=1.0`, using min-max normalization
+/// 
+/// A first pass finds the minimum and the maximum, then the iterator is reset and yields `(x - min) / (max - min)` for each item. If all the items are equal, it yields `0.0` for each of them
+/// 
+/// # Example
+/// ```
+/// use iterators_collection::{normalize, IntoResettable};
+/// 
+/// let array = [10, 15, 20, 30];
+/// let iter = normalize(array.iter().cloned().resettable());
+/// 
+/// assert_eq!(iter.collect::<Vec<f64>>(), vec![0.0, 0.25, 0.5, 1.0]);
+/// ```
+pub fn normalize<I>(mut iterator: I) -> Normalize<I>
+where
+    I: self::ResettableIterator,
+    I::Item: Into<f64>,
+{
+    iterator.reset();
+    let (min, max) = iterator.by_ref().fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), x| {
+        let x = x.into();
+        (min.min(x), max.max(x))
+    });
+
+    iterator.reset();
+    Normalize {
+        iterator,
+        min,
+        max,
+    }
+}
+
+/// The iterator returned by `normalize`
+#[derive(Clone)]
+pub struct Normalize<I> {
+    iterator: I,
+    min: f64,
+    max: f64,
+}
+
+impl<I> Normalize<I> {
+    /// Returns the minimum and the maximum of the items, found during the first pass
+    pub fn bounds(&self) -> (f64, f64) {
+        (self.min, self.max)
+    }
+}
+
+impl<I> Iterator for Normalize<I>
+where
+    I: Iterator,
+    I::Item: Into<f64>,
+{
+    type Item = f64;
+
+    fn next(&mut self) -> Option<f64> {
+        let x = self.iterator.next()?.into();
+
+        if self.max > self.min {
+            Some((x - self.min) / (self.max - self.min))
+        } else {
+            Some(0.0)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iterator.size_hint()
+    }
+}
+
+crate::impl_resettable_iterator!([I] Normalize<I>, iterator: I, where I::Item: Into<f64>);
+
+crate::impl_child_iterator!([I] Normalize<I>, iterator: I, where I::Item: Into<f64>);
+crate::impl_describe!([I] Normalize<I>, iterator: I);
+
+/// Creates the cartesian product of two iterators, yielding `(a, b)` for each item `a` of `outer` and each item `b` of `inner`
+/// 
+/// Unlike the usual implementations, `inner` is not cloned but reset for each item of `outer`, so it works with iterators that can't be cloned like `DoubleIterator`. Only the items of `outer` are cloned
+/// 
+/// # Example
+/// ```
+/// use iterators_collection::{product, IntoResettable};
+/// 
+/// let letters = ['a', 'b'];
+/// let iter = product(0..2, letters.iter().cloned().resettable());
+/// 
+/// assert_eq!(iter.size_hint(), (4, Some(4)));
+/// assert_eq!(iter.collect::<Vec<(i32, char)>>(), vec![(0, 'a'), (0, 'b'), (1, 'a'), (1, 'b')]);
+/// ```
+pub fn product<A, B>(outer: A, mut inner: B) -> CartesianProduct<A, B>
+where
+    A: Iterator,
+    A::Item: Clone,
+    B: self::ResettableIterator,
+{
+    inner.reset();
+
+    CartesianProduct {
+        outer,
+        inner_hint: inner.size_hint(),
+        inner,
+        current: None,
+    }
+}
+
+/// The iterator returned by `product`
+/// 
+/// Its parent as a `ChildIterator` is the outer iterator
+pub struct CartesianProduct<A, B>
+where
+    A: Iterator,
+{
+    outer: A,
+    inner: B,
+    inner_hint: (usize, Option<usize>),
+    current: Option<A::Item>,
+}
+
+impl<A, B> Iterator for CartesianProduct<A, B>
+where
+    A: Iterator,
+    A::Item: Clone,
+    B: self::ResettableIterator,
+{
+    type Item = (A::Item, B::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.current.is_none() {
+                self.current = Some(self.outer.next()?);
+
+                self.inner.reset();
+                self.inner_hint = self.inner.size_hint();
+            }
+
+            match (&self.current, self.inner.next()) {
+                (Some(a), Some(b)) => return Some((a.clone(), b)),
+                _                  => self.current = None,
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (current_lower, current_upper) = if self.current.is_some() {
+            self.inner.size_hint()
+        } else {
+            (0, Some(0))
+        };
+        let (outer_lower, outer_upper) = self.outer.size_hint();
+        let (inner_lower, inner_upper) = self.inner_hint;
+
+        let lower = outer_lower.saturating_mul(inner_lower).saturating_add(current_lower);
+        let upper = match (outer_upper, inner_upper, current_upper) {
+            (Some(outer), Some(inner), Some(current)) => outer.checked_mul(inner).and_then(|rest| rest.checked_add(current)),
+            _                                         => None,
+        };
+
+        (lower, upper)
+    }
+}
+
+impl<A, B> self::ResettableIterator for CartesianProduct<A, B>
+where
+    A: self::ResettableIterator,
+    A::Item: Clone,
+    B: self::ResettableIterator,
+{
+    fn reset(&mut self) {
+        self.outer.reset();
+        self.current = None;
+    }
+}
+
+crate::impl_child_iterator!([A, B] CartesianProduct<A, B>, outer: A, where A::Item: Clone, B: self::ResettableIterator);
+crate::impl_describe!([A, B] CartesianProduct<A, B>, outer: A, where A: Iterator);
+
+/// Counts the passes over a resettable iterator and the items yielded during the current pass, which is useful to debug multi-pass algorithms
+/// 
+/// A hook can be given to be run on each reset. It gets the index of the pass which is ending and the number of items yielded during it
+/// 
+/// # Example
+/// ```
+/// use iterators_collection::{median_of, IntoResettable, PassCounter};
+/// 
+/// let array = [5, 3, 8, 1, 9];
+/// let mut iter = PassCounter::with_hook(array.iter().cloned().resettable(), |pass, items| {
+///     println!("Pass {} yielded {} items", pass, items);
+/// });
+/// 
+/// assert_eq!(median_of(&mut iter), Some(5.0));
+/// println!("The median has been found in {} passes", iter.pass());
+/// ```
+pub struct PassCounter<I, H = fn(usize, usize)> {
+    iterator: I,
+    hook: Option<H>,
+    pass: usize,
+    items: usize,
+}
+
+impl<I> PassCounter<I>
+where
+    I: Iterator,
+{
+    /// Returns a new `PassCounter` without any hook
+    pub fn new(iterator: I) -> Self {
+        Self {
+            iterator,
+            hook: None,
+            pass: 0,
+            items: 0,
+        }
+    }
+}
+
+impl<I, H> PassCounter<I, H>
+where
+    I: Iterator,
+    H: FnMut(usize, usize),
+{
+    /// Returns a new `PassCounter` running `hook` on each reset
+    pub fn with_hook(iterator: I, hook: H) -> Self {
+        Self {
+            iterator,
+            hook: Some(hook),
+            pass: 0,
+            items: 0,
+        }
+    }
+
+    /// Returns the index of the current pass, starting from 0. It is the number of times the iterator has been reset
+    pub fn pass(&self) -> usize {
+        self.pass
+    }
+
+    /// Returns the number of items yielded since the last reset
+    pub fn items_this_pass(&self) -> usize {
+        self.items
+    }
+}
+
+impl<I, H> Iterator for PassCounter<I, H>
+where
+    I: Iterator,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        let item = self.iterator.next()?;
+        self.items += 1;
+
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iterator.size_hint()
+    }
+}
+
+impl<I, H> self::ResettableIterator for PassCounter<I, H>
+where
+    I: self::ResettableIterator,
+    H: FnMut(usize, usize),
+{
+    fn reset(&mut self) {
+        if let Some(hook) = &mut self.hook {
+            hook(self.pass, self.items);
+        }
+
+        self.iterator.reset();
+        self.pass += 1;
+        self.items = 0;
+    }
+}
+
+crate::impl_child_iterator!([I, H] PassCounter<I, H>, iterator: I);
+
+impl<I, H> crate::child::Describe for PassCounter<I, H>
+where
+    I: crate::child::Describe,
+{
+    fn describe_stage(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "PassCounter(pass={}, items={})", self.pass, self.items)
+    }
+
+    fn describe_parent(&self) -> Option<&dyn crate::child::Describe> {
+        Some(&self.iterator)
+    }
+}
+
+/// A snapshot of the statistics recorded by an `Instrument`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct InstrumentStats {
+    /// The number of items yielded by the parent
+    pub items: usize,
+
+    /// The number of calls to `next`
+    pub next_calls: usize,
+
+    /// The number of calls to `next` which returned `None`
+    pub nones: usize,
+
+    /// The wall time spent inside the `next` method of the parent
+    pub elapsed: std::time::Duration,
+}
+
+/// Records statistics about the calls to `next` on its parent, which is useful to find the slow stage of a chain of adapters
+/// 
+/// The statistics are kept across resets and can be read at any moment with `stats`. They are only cleared by `reset_stats`
+/// 
+/// # Example
+/// ```
+/// use iterators_collection::Instrument;
+/// use iterators_collection::child::ChildIterator;
+/// use iterators_collection::filter::Exclude;
+/// 
+/// let iter = Instrument::new(0..5);
+/// let mut iter = Exclude::with_blacklist(iter, vec![1, 3]);
+/// 
+/// assert_eq!(iter.next(), Some(0));
+/// assert_eq!(iter.next(), Some(2));
+/// 
+/// // The parent of `Exclude` had to yield 3 items to get 2 of them
+/// let stats = iter.get_parent().stats();
+/// assert_eq!(stats.items, 3);
+/// assert_eq!(stats.next_calls, 3);
+/// assert_eq!(stats.nones, 0);
+/// ```
+pub struct Instrument<I> {
+    iterator: I,
+    stats: InstrumentStats,
+}
+
+impl<I> Instrument<I> {
+    /// Returns a new `Instrument` with empty statistics
+    pub fn new(iterator: I) -> Self {
+        Self {
+            iterator,
+            stats: InstrumentStats::default(),
+        }
+    }
+
+    /// Returns a snapshot of the statistics recorded so far
+    pub fn stats(&self) -> InstrumentStats {
+        self.stats
+    }
+
+    /// Clears the statistics
+    pub fn reset_stats(&mut self) {
+        self.stats = InstrumentStats::default();
+    }
+}
+
+impl<I> Iterator for Instrument<I>
+where
+    I: Iterator,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        let start = std::time::Instant::now();
+        let item = self.iterator.next();
+        self.stats.elapsed += start.elapsed();
+
+        self.stats.next_calls += 1;
+        match item {
+            Some(_) => self.stats.items += 1,
+            None    => self.stats.nones += 1,
+        }
+
+        item
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iterator.size_hint()
+    }
+}
+
+crate::impl_resettable_iterator!([I] Instrument<I>, iterator: I);
+crate::impl_child_iterator!([I] Instrument<I>, iterator: I);
+
+impl<I> crate::child::Describe for Instrument<I>
+where
+    I: crate::child::Describe,
+{
+    fn describe_stage(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Instrument(items={}, calls={}, nones={}, time={:?})",
+            self.stats.items,
+            self.stats.next_calls,
+            self.stats.nones,
+            self.stats.elapsed,
+        )
+    }
+
+    fn describe_parent(&self) -> Option<&dyn crate::child::Describe> {
+        Some(&self.iterator)
+    }
+}
+
+/// An iterator able to look at any number of items ahead without consuming them
+/// 
+/// `peek` and `peek_mut` move a peek cursor forward, so successive calls look further ahead. The cursor goes back to the next item with `reset_peek` and after each call to `next`. `peek_nth` ignores the cursor
+/// 
+/// The peeked items are buffered, and the buffer is cleared on reset
+/// 
+/// # Example
+/// ```
+/// use iterators_collection::MultiPeek;
+/// 
+/// let mut iter = MultiPeek::new("a+=1".chars());
+/// 
+/// assert_eq!(iter.peek(), Some(&'a'));
+/// assert_eq!(iter.peek(), Some(&'+'));
+/// assert_eq!(iter.peek(), Some(&'='));
+/// assert_eq!(iter.peek_nth(3), Some(&'1'));
+/// 
+/// iter.reset_peek();
+/// assert_eq!(iter.peek(), Some(&'a'));
+/// assert_eq!(iter.next(), Some('a'));
+/// assert_eq!(iter.peek(), Some(&'+'));
+/// ```
+pub struct MultiPeek<I>
+where
+    I: Iterator,
+{
+    iterator: I,
+    buffer: std::collections::VecDeque<I::Item>,
+    cursor: usize,
+}
+
+impl<I> MultiPeek<I>
+where
+    I: Iterator,
+{
+    /// Returns a new `MultiPeek` with an empty buffer
+    pub fn new(iterator: I) -> Self {
+        Self {
+            iterator,
+            buffer: std::collections::VecDeque::new(),
+            cursor: 0,
+        }
+    }
+
+    /// Pulls items from the parent until the buffer contains the `n`th one. Returns `false` if the parent is exhausted before
+    fn fill(&mut self, n: usize) -> bool {
+        while self.buffer.len() <= n {
+            match self.iterator.next() {
+                None       => return false,
+                Some(item) => self.buffer.push_back(item),
+            }
+        }
+
+        true
+    }
+
+    /// Returns a reference to the item under the peek cursor and moves the cursor forward. Returns `None` without moving the cursor if there is no more item
+    pub fn peek(&mut self) -> Option<&I::Item> {
+        self.peek_mut().map(|item| &*item)
+    }
+
+    /// Returns a mutable reference to the item under the peek cursor and moves the cursor forward. Returns `None` without moving the cursor if there is no more item
+    pub fn peek_mut(&mut self) -> Option<&mut I::Item> {
+        if !self.fill(self.cursor) {
+            return None;
+        }
+
+        self.cursor += 1;
+        self.buffer.get_mut(self.cursor - 1)
+    }
+
+    /// Returns a reference to the `n`th next item, starting from 0, without moving the peek cursor
+    pub fn peek_nth(&mut self, n: usize) -> Option<&I::Item> {
+        self.peek_nth_mut(n).map(|item| &*item)
+    }
+
+    /// Returns a mutable reference to the `n`th next item, starting from 0, without moving the peek cursor
+    pub fn peek_nth_mut(&mut self, n: usize) -> Option<&mut I::Item> {
+        if !self.fill(n) {
+            return None;
+        }
+
+        self.buffer.get_mut(n)
+    }
+
+    /// Moves the peek cursor back to the next item
+    pub fn reset_peek(&mut self) {
+        self.cursor = 0;
+    }
+}
+
+impl<I> Iterator for MultiPeek<I>
+where
+    I: Iterator,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        self.cursor = 0;
+
+        match self.buffer.pop_front() {
+            None       => self.iterator.next(),
+            Some(item) => Some(item),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iterator.size_hint();
+
+        (
+            lower.saturating_add(self.buffer.len()),
+            upper.and_then(|upper| upper.checked_add(self.buffer.len())),
+        )
+    }
+}
+
+impl<I> self::ResettableIterator for MultiPeek<I>
+where
+    I: self::ResettableIterator,
+{
+    fn reset(&mut self) {
+        self.iterator.reset();
+        self.buffer.clear();
+        self.cursor = 0;
+    }
+}
+
+crate::impl_child_iterator!([I] MultiPeek<I>, iterator: I);
+crate::impl_describe!([I] MultiPeek<I>, iterator: I, where I: Iterator);
+
+/// An iterator on which items can be put back, to be yielded again by the next calls to `next`
+/// 
+/// The items put back are yielded in the reverse order, the last one first. They are forgotten on reset
+/// 
+/// # Example
+/// ```
+/// use iterators_collection::PutBack;
+/// 
+/// let mut iter = PutBack::new(1..4);
+/// 
+/// let first = iter.next().unwrap();
+/// iter.put_back(first);
+/// iter.put_back(0);
+/// 
+/// assert_eq!(iter.collect::<Vec<i32>>(), vec![0, 1, 2, 3]);
+/// ```
+pub struct PutBack<I>
+where
+    I: Iterator,
+{
+    iterator: I,
+    buffer: Vec<I::Item>,
+}
+
+impl<I> PutBack<I>
+where
+    I: Iterator,
+{
+    /// Returns a new `PutBack` without any item put back
+    pub fn new(iterator: I) -> Self {
+        Self {
+            iterator,
+            buffer: Vec::new(),
+        }
+    }
+
+    /// Puts `item` back, so it will be yielded by the next call to `next`
+    pub fn put_back(&mut self, item: I::Item) {
+        self.buffer.push(item);
+    }
+
+    /// Returns a mutable reference to the next item without consuming it
+    pub fn peek_mut(&mut self) -> Option<&mut I::Item> {
+        if self.buffer.is_empty() {
+            let item = self.iterator.next()?;
+            self.buffer.push(item);
+        }
+
+        self.buffer.last_mut()
+    }
+}
+
+impl<I> Iterator for PutBack<I>
+where
+    I: Iterator,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        match self.buffer.pop() {
+            None       => self.iterator.next(),
+            Some(item) => Some(item),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iterator.size_hint();
+
+        (
+            lower.saturating_add(self.buffer.len()),
+            upper.and_then(|upper| upper.checked_add(self.buffer.len())),
+        )
+    }
+}
+
+impl<I> self::ResettableIterator for PutBack<I>
+where
+    I: self::ResettableIterator,
+{
+    fn reset(&mut self) {
+        self.iterator.reset();
+        self.buffer.clear();
+    }
+}
+
+crate::impl_child_iterator!([I] PutBack<I>, iterator: I);
+crate::impl_describe!([I] PutBack<I>, iterator: I, where I: Iterator);
+
+
+/// A trait adding the `resettable` method to every cloneable iterator
+pub trait IntoResettable: Iterator + Clone + Sized {
+    /// Creates a `Restartable` from the current iterator, making it resettable
+    /// 
+    /// # Example
+    /// ```
+    /// use iterators_collection::{IntoResettable, ResettableIterator};
+    /// 
+    /// let mut iter = (1..4).resettable();
+    /// assert_eq!(iter.next(), Some(1));
+    /// assert_eq!(iter.next(), Some(2));
+    /// 
+    /// iter.reset();
+    /// assert_eq!(iter.collect::<Vec<i32>>(), vec![1, 2, 3]);
+    /// ```
+    fn resettable(self) -> Restartable<Self> {
+        Restartable::new(self)
+    }
+}
+
+impl<I> IntoResettable for I
+where
+    I: Iterator + Clone,
+{
+}
+
+/// Makes any cloneable iterator resettable. It stores a copy of the iterator as it was when created, and restores it on `reset`
+/// 
+/// You can create it with the `resettable` method of the `IntoResettable` trait
+#[derive(Clone, Debug)]
+pub struct Restartable<I> {
+    initial: I,
+    iterator: I,
+}
+
+impl<I> Restartable<I>
+where
+    I: Iterator + Clone,
+{
+    /// Returns a new `Restartable` which will go back to the current position of `iterator` when reset
+    pub fn new(iterator: I) -> Self {
+        Self {
+            initial: iterator.clone(),
+            iterator,
+        }
+    }
+}
+
+impl<I> Iterator for Restartable<I>
+where
+    I: Iterator,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        self.iterator.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iterator.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<I::Item> {
+        self.iterator.nth(n)
+    }
+}
+
+impl<I> DoubleEndedIterator for Restartable<I>
+where
+    I: DoubleEndedIterator,
+{
+    fn next_back(&mut self) -> Option<I::Item> {
+        self.iterator.next_back()
+    }
+}
+
+impl<I> ExactSizeIterator for Restartable<I>
+where
+    I: ExactSizeIterator,
+{
+}
+
+impl<I> std::iter::FusedIterator for Restartable<I>
+where
+    I: std::iter::FusedIterator,
+{
+}
+
+impl<I> self::ResettableIterator for Restartable<I>
+where
+    I: Iterator + Clone,
+{
+    fn reset(&mut self) {
+        self.iterator = self.initial.clone();
+    }
+}
+
+impl<I> self::CheckpointIterator for Restartable<I>
+where
+    I: Iterator + Clone,
+{
+    type Mark = I;
+
+    fn checkpoint(&self) -> I {
+        self.iterator.clone()
+    }
+
+    fn restore(&mut self, mark: I) {
+        self.iterator = mark;
+    }
+}
+
+crate::impl_child_iterator!([I] Restartable<I>, iterator: I);
+crate::impl_describe!([I] Restartable<I>, iterator: I);
+
+
+/// The error returned by `Replay::try_reset` when more items than the limit were pulled from the source
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReplayOverflow {
+    /// The maximum number of items the `Replay` was allowed to record
+    pub limit: usize,
+}
+
+impl std::fmt::Display for ReplayOverflow {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "cannot replay more than {} items", self.limit)
+    }
+}
+
+impl std::error::Error for ReplayOverflow {}
+
+/// Makes any iterator resettable by recording the items it yields. After a reset, the recorded items are replayed, then the iteration goes on from the source
+/// 
+/// Unlike `Restartable`, it doesn't need the source to be cloneable, so it works with iterators reading a channel or the standard input. The items are cloned when replayed
+/// 
+/// # Example
+/// ```
+/// use iterators_collection::{Replay, ResettableIterator};
+/// 
+/// let (sender, receiver) = std::sync::mpsc::channel();
+/// for i in 0..5 {
+///     sender.send(i).unwrap();
+/// }
+/// drop(sender);
+/// 
+/// let mut iter = Replay::new(receiver.into_iter());
+/// assert_eq!(iter.next(), Some(0));
+/// assert_eq!(iter.next(), Some(1));
+/// 
+/// iter.reset();
+/// assert_eq!(iter.collect::<Vec<i32>>(), vec![0, 1, 2, 3, 4]);
+/// ```
+pub struct Replay<I>
+where
+    I: Iterator,
+{
+    source: I,
+    buffer: Vec<I::Item>,
+    position: usize,
+    limit: Option<usize>,
+    overflowed: bool,
+}
+
+impl<I> Replay<I>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+    /// Returns a new `Replay` recording every item of `source`
+    pub fn new(source: I) -> Self {
+        Self {
+            source,
+            buffer: Vec::new(),
+            position: 0,
+            limit: None,
+            overflowed: false,
+        }
+    }
+
+    /// Returns a new `Replay` recording at most `limit` items
+    /// 
+    /// When the limit is exceeded, the recording stops but the iteration goes on from the source so no item is lost. Only the next reset fails, see `try_reset`
+    pub fn with_limit(source: I, limit: usize) -> Self {
+        Self {
+            limit: Some(limit),
+            ..Self::new(source)
+        }
+    }
+
+    /// Resets the iterator to its first item or returns an error if some items could not be recorded because of the limit. In this case, the iterator is left unchanged
+    pub fn try_reset(&mut self) -> Result<(), ReplayOverflow> {
+        if self.overflowed {
+            return Err(ReplayOverflow {
+                limit: self.limit.unwrap_or(0),
+            });
+        }
+
+        self.position = 0;
+        Ok(())
+    }
+
+    /// Returns `true` if the limit has been exceeded, making the iterator impossible to reset
+    pub fn is_overflowed(&self) -> bool {
+        self.overflowed
+    }
+
+    /// Returns the items recorded so far
+    pub fn recorded(&self) -> &[I::Item] {
+        &self.buffer
+    }
+}
+
+impl<I> Iterator for Replay<I>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        if let Some(item) = self.buffer.get(self.position) {
+            self.position += 1;
+            return Some(item.clone());
+        }
+
+        let item = self.source.next()?;
+        if !self.overflowed {
+            if self.limit.is_some_and(|limit| self.buffer.len() >= limit) {
+                self.overflowed = true;
+            } else {
+                self.buffer.push(item.clone());
+                self.position += 1;
+            }
+        }
+
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let replayed = self.buffer.len() - self.position;
+        let (lower, upper) = self.source.size_hint();
+
+        (
+            lower.saturating_add(replayed),
+            upper.and_then(|upper| upper.checked_add(replayed)),
+        )
+    }
+}
+
+impl<I> self::ResettableIterator for Replay<I>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+    /// Resets the iterator to its first item
+    /// 
+    /// # Panics
+    /// Panics if the limit has been exceeded. Use `try_reset` to handle this case
+    fn reset(&mut self) {
+        if let Err(error) = self.try_reset() {
+            panic!("{}", error);
+        }
+    }
+}
+
+impl<I> self::CheckpointIterator for Replay<I>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+    type Mark = usize;
+
+    fn checkpoint(&self) -> usize {
+        self.position
+    }
+
+    /// Sets the iterator back to a position returned by its `checkpoint` method
+    /// 
+    /// # Panics
+    /// Panics if the limit has been exceeded, like `reset` does
+    fn restore(&mut self, mark: usize) {
+        assert!(!self.overflowed, "{}", ReplayOverflow { limit: self.limit.unwrap_or(0) });
+        assert!(mark <= self.buffer.len());
+
+        self.position = mark;
+    }
+}
+
+crate::impl_child_iterator!([I] Replay<I>, source: I, where I::Item: Clone);
+crate::impl_describe!([I] Replay<I>, source: I, where I: Iterator);
+
 
 #[cfg(test)]
 mod tests;
@@ -69,3 +69,505 @@ fn resettable_map_resets_well() {
 
     assert_eq!(iter.next(), Some(1));
 }
+
+#[test]
+fn restartable_resets_well() {
+    let array = [1, 2, 3, 4];
+    let mut iter = array.iter().resettable();
+
+    iter.next();
+    iter.next();
+    assert_eq!(iter.len(), 2);
+
+    iter.reset();
+    assert_eq!(iter.cloned().collect::<Vec<i32>>(), vec![1, 2, 3, 4]);
+}
+
+#[test]
+fn restartable_restarts_from_where_it_was_created() {
+    let mut chars = "abcd".chars();
+    chars.next();
+
+    let mut iter = chars.resettable();
+    assert_eq!(iter.next_back(), Some('d'));
+
+    iter.reset();
+    assert_eq!(iter.collect::<String>(), "bcd");
+}
+
+#[test]
+fn restartable_works_with_resettable_map() {
+    let mut iter = (0..3).resettable().resettable_map(|x| x * 2);
+
+    assert_eq!(iter.next(), Some(0));
+    assert_eq!(iter.next(), Some(2));
+
+    iter.reset();
+    assert_eq!(iter.collect::<Vec<i32>>(), vec![0, 2, 4]);
+}
+
+#[test]
+fn replay_replays_then_continues() {
+    let mut iter = Replay::new(Count { begin: 0, cur: 0, end: 5 });
+
+    assert_eq!(iter.next(), Some(0));
+    assert_eq!(iter.next(), Some(1));
+
+    iter.reset();
+    assert_eq!(iter.size_hint(), (2, None));
+    assert_eq!(iter.by_ref().collect::<Vec<u32>>(), vec![0, 1, 2, 3, 4]);
+
+    iter.reset();
+    assert_eq!(iter.collect::<Vec<u32>>(), vec![0, 1, 2, 3, 4]);
+}
+
+#[test]
+fn replay_with_limit() {
+    let mut iter = Replay::with_limit(0..10, 3);
+
+    assert_eq!(iter.by_ref().take(3).count(), 3);
+    assert!(iter.try_reset().is_ok());
+    assert!(!iter.is_overflowed());
+
+    // The iteration goes on even once the limit is exceeded
+    assert_eq!(iter.by_ref().collect::<Vec<i32>>(), (0..10).collect::<Vec<i32>>());
+    assert!(iter.is_overflowed());
+    assert_eq!(iter.recorded(), &[0, 1, 2]);
+    assert_eq!(iter.try_reset(), Err(ReplayOverflow { limit: 3 }));
+}
+
+#[test]
+#[should_panic]
+fn replay_reset_panics_once_overflowed() {
+    let mut iter = Replay::with_limit(0..10, 3);
+
+    iter.by_ref().for_each(drop);
+    iter.reset();
+}
+
+/// Collects `iter`, resets it and checks the second pass yields the same items
+fn collect_twice<I>(iter: &mut I) -> Vec<I::Item>
+where
+    I: ResettableIterator,
+    I::Item: PartialEq + std::fmt::Debug,
+{
+    let first: Vec<I::Item> = iter.by_ref().collect();
+    iter.reset();
+    let second: Vec<I::Item> = iter.by_ref().collect();
+
+    assert_eq!(first, second);
+    first
+}
+
+#[test]
+fn resettable_filters_reset_well() {
+    let count = || Count { begin: 0, cur: 0, end: 10 };
+
+    let mut iter = count().resettable_filter(|x| x % 3 == 0);
+    assert_eq!(collect_twice(&mut iter), vec![0, 3, 6, 9]);
+
+    let mut iter = count().resettable_filter_map(|x| if x % 4 == 0 { Some(x / 4) } else { None });
+    assert_eq!(collect_twice(&mut iter), vec![0, 1, 2]);
+
+    let mut iter = count().resettable_take_while(|x| *x < 4);
+    assert_eq!(collect_twice(&mut iter), vec![0, 1, 2, 3]);
+
+    let mut iter = count().resettable_skip_while(|x| *x < 7);
+    assert_eq!(collect_twice(&mut iter), vec![7, 8, 9]);
+}
+
+#[test]
+fn resettable_positional_adapters_reset_well() {
+    let count = || Count { begin: 0, cur: 0, end: 10 };
+
+    let mut iter = count().resettable_take(3);
+    assert_eq!(collect_twice(&mut iter), vec![0, 1, 2]);
+
+    let mut iter = count().resettable_skip(7);
+    assert_eq!(collect_twice(&mut iter), vec![7, 8, 9]);
+
+    let mut iter = count().resettable_step_by(4);
+    assert_eq!(collect_twice(&mut iter), vec![0, 4, 8]);
+
+    let mut iter = count().resettable_skip(8).resettable_enumerate();
+    assert_eq!(collect_twice(&mut iter), vec![(0, 8), (1, 9)]);
+}
+
+#[test]
+fn resettable_combining_adapters_reset_well() {
+    let count = |begin, end| Count { begin, cur: begin, end };
+
+    let mut iter = count(0, 3).resettable_zip(count(5, 10));
+    assert_eq!(collect_twice(&mut iter), vec![(0, 5), (1, 6), (2, 7)]);
+
+    let mut iter = count(0, 2).resettable_chain(count(5, 7));
+    assert_eq!(collect_twice(&mut iter), vec![0, 1, 5, 6]);
+
+    let mut iter = count(1, 4).resettable_flat_map(|x| vec![x; x as usize]);
+    assert_eq!(collect_twice(&mut iter), vec![1, 2, 2, 3, 3, 3]);
+
+    let mut iter = count(1, 5).resettable_scan(0, |sum, x| {
+        *sum += x;
+        Some(*sum)
+    });
+    assert_eq!(collect_twice(&mut iter), vec![1, 3, 6, 10]);
+}
+
+#[test]
+fn resettable_inspect_runs_on_each_pass() {
+    let mut seen = Vec::new();
+    let mut iter = Count { begin: 0, cur: 0, end: 3 }.resettable_inspect(|x| seen.push(*x));
+
+    iter.by_ref().for_each(drop);
+    iter.reset();
+    iter.for_each(drop);
+
+    assert_eq!(seen, vec![0, 1, 2, 0, 1, 2]);
+}
+
+#[test]
+fn resettable_adapters_reset_in_the_middle() {
+    let mut iter = Count { begin: 0, cur: 0, end: 20 }
+        .resettable_skip(2)
+        .resettable_step_by(3)
+        .resettable_take(4);
+
+    assert_eq!(iter.next(), Some(2));
+    assert_eq!(iter.next(), Some(5));
+
+    iter.reset();
+    assert_eq!(iter.collect::<Vec<u32>>(), vec![2, 5, 8, 11]);
+}
+
+#[test]
+fn resettable_map_forwards_capabilities() {
+    let array = [1, 2, 3, 4];
+    let mut iter = array.iter().resettable().resettable_map(|x| x * 10);
+
+    assert_eq!(iter.size_hint(), (4, Some(4)));
+    assert_eq!(iter.len(), 4);
+    assert_eq!(iter.next_back(), Some(40));
+    assert_eq!(iter.clone().fold(Vec::new(), |mut acc, x| { acc.push(x); acc }), vec![10, 20, 30]);
+    assert_eq!(iter.clone().rev().collect::<Vec<i32>>(), vec![30, 20, 10]);
+    assert!(format!("{:?}", iter).starts_with("ResettableMap { iterator: Restartable {"));
+}
+
+#[test]
+fn checkpoint_through_resettable_map() {
+    let mut iter = (0..10).resettable().resettable_map(|x| x * x);
+    iter.next();
+    iter.next();
+
+    let mark = iter.checkpoint();
+    assert_eq!(iter.next(), Some(4));
+    assert_eq!(iter.next(), Some(9));
+
+    iter.restore(mark);
+    assert_eq!(iter.next(), Some(4));
+}
+
+#[test]
+fn replay_checkpoint() {
+    let mut iter = Replay::new(Count { begin: 0, cur: 0, end: 6 });
+    iter.next();
+
+    let mark = iter.checkpoint();
+    iter.by_ref().for_each(drop);
+
+    iter.restore(mark);
+    assert_eq!(iter.collect::<Vec<u32>>(), vec![1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn slice_cursor_goes_both_ways() {
+    let array = [1, 2, 3];
+    let mut iter = SliceCursor::new(&array).resettable_map(|x| x * 2);
+
+    assert_eq!(iter.next(), Some(2));
+    assert_eq!(iter.next(), Some(4));
+    assert_eq!(iter.prev(), Some(4));
+    assert_eq!(iter.next(), Some(4));
+    assert_eq!(iter.next(), Some(6));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.prev(), Some(6));
+
+    iter.reset();
+    assert_eq!(iter.prev(), None);
+    assert_eq!(iter.next(), Some(2));
+}
+
+#[test]
+fn cycle_reset_repeats() {
+    let mut iter = Count { begin: 0, cur: 0, end: 3 };
+    iter.next();
+
+    let iter = iter.cycle_reset();
+    assert_eq!(iter.take(7).collect::<Vec<u32>>(), vec![1, 2, 0, 1, 2, 0, 1]);
+}
+
+#[test]
+fn cycle_reset_stops_on_empty_pass() {
+    // Already consumed: the first pass is empty but the next ones are not
+    let iter = Count { begin: 0, cur: 2, end: 2 };
+    assert_eq!(iter.cycle_n(2).collect::<Vec<u32>>(), vec![0, 1]);
+
+    // Empty after reset
+    let mut iter = Count { begin: 3, cur: 0, end: 3 }.cycle_reset();
+    assert_eq!(iter.by_ref().collect::<Vec<u32>>(), vec![0, 1, 2]);
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn cycle_n_resets_well() {
+    let mut iter = Count { begin: 0, cur: 0, end: 2 }.cycle_n(3);
+    assert_eq!(iter.by_ref().collect::<Vec<u32>>(), vec![0, 1, 0, 1, 0, 1]);
+
+    iter.reset();
+    assert_eq!(iter.count(), 6);
+
+    assert_eq!(Count { begin: 0, cur: 0, end: 2 }.cycle_n(0).next(), None);
+}
+
+#[test]
+fn from_fn_resettable_plugs_into_adapters() {
+    let iter = from_fn_resettable(0, |n: &mut u32| {
+        *n += 1;
+        if *n <= 5 { Some(*n) } else { None }
+    });
+    let mut iter = crate::filter::Exclude::with_blacklist(iter.resettable_map(|x| x * 2), vec![4]);
+
+    assert_eq!(iter.by_ref().collect::<Vec<u32>>(), vec![2, 6, 8, 10]);
+
+    iter.reset();
+    assert_eq!(iter.collect::<Vec<u32>>(), vec![2, 6, 8, 10]);
+}
+
+#[test]
+fn unfold_and_successors_reset_well() {
+    let mut iter = unfold_resettable((0, 3), |(n, left): (u32, u32)| {
+        if left == 0 { None } else { Some((n, (n + 2, left - 1))) }
+    });
+    assert_eq!(collect_twice(&mut iter), vec![0, 2, 4]);
+
+    let mut iter = successors_resettable(Some(100), |n: &u32| if *n > 10 { Some(n / 3) } else { None });
+    assert_eq!(collect_twice(&mut iter), vec![100, 33, 11, 3]);
+
+    let mut iter = successors_resettable(None, |n: &u32| Some(n + 1));
+    assert_eq!(collect_twice(&mut iter), vec![]);
+}
+
+#[test]
+fn resettable_map_with_resets_its_state() {
+    let mut iter = Count { begin: 0, cur: 0, end: 4 }.resettable_map_with(10, |offset, x| {
+        *offset += 1;
+        x + *offset
+    });
+
+    assert_eq!(iter.next(), Some(11));
+    assert_eq!(iter.next(), Some(13));
+
+    iter.reset();
+    assert_eq!(collect_twice(&mut iter), vec![11, 13, 15, 17]);
+}
+
+#[test]
+fn two_pass_mean_variance_works() {
+    let mut iter = Count { begin: 1, cur: 3, end: 6 };
+    assert_eq!(two_pass_mean_variance(&mut iter), Some((3.0, 2.0)));
+
+    let mut iter = Count { begin: 0, cur: 0, end: 0 };
+    assert_eq!(two_pass_mean_variance(&mut iter), None);
+}
+
+#[test]
+fn median_of_works() {
+    let array = [5.5, -1.0, 3.0, 3.0, 12.0, 0.25, 3.0];
+    let mut iter = array.iter().cloned().resettable();
+    assert_eq!(median_of(&mut iter), Some(3.0));
+
+    let array = [9, 1, 8, 2, 7, 3];
+    let mut iter = array.iter().cloned().resettable();
+    assert_eq!(median_of(&mut iter), Some(5.0));
+
+    let array = [f64::MIN_POSITIVE, 1e300, -1e-300, 0.0];
+    let mut iter = array.iter().cloned().resettable();
+    assert_eq!(median_of(&mut iter), Some(f64::MIN_POSITIVE / 2.0));
+
+    let mut iter = Count { begin: 4, cur: 4, end: 5 };
+    assert_eq!(median_of(&mut iter), Some(4.0));
+}
+
+#[test]
+fn median_of_agrees_with_sorting() {
+    let values: Vec<i32> = (0..101).map(|x| (x * 37 + 11) % 23).collect();
+    let mut sorted = values.clone();
+    sorted.sort();
+
+    let mut iter = values.iter().cloned().resettable();
+    assert_eq!(median_of(&mut iter), Some(sorted[50] as f64));
+
+    let mut iter = values[1..].iter().cloned().resettable();
+    let mut sorted: Vec<i32> = values[1..].to_vec();
+    sorted.sort();
+    assert_eq!(median_of(&mut iter), Some((sorted[49] + sorted[50]) as f64 / 2.0));
+}
+
+#[test]
+fn normalize_resets_well() {
+    let mut iter = normalize(Count { begin: 2, cur: 2, end: 7 });
+    assert_eq!(iter.bounds(), (2.0, 6.0));
+    assert_eq!(collect_twice(&mut iter), vec![0.0, 0.25, 0.5, 0.75, 1.0]);
+
+    let mut iter = normalize(Count { begin: 2, cur: 2, end: 3 });
+    assert_eq!(iter.next(), Some(0.0));
+}
+
+#[test]
+fn product_resets_the_inner_iterator() {
+    let outer = Count { begin: 0, cur: 0, end: 3 };
+    let mut inner = Count { begin: 5, cur: 5, end: 7 };
+    inner.next();
+
+    let mut iter = product(outer, inner);
+    assert_eq!(
+        collect_twice(&mut iter),
+        vec![(0, 5), (0, 6), (1, 5), (1, 6), (2, 5), (2, 6)],
+    );
+}
+
+#[test]
+fn product_size_hint() {
+    let mut iter = product(0..3, (0..4).resettable());
+
+    for remaining in (0..=12).rev() {
+        assert_eq!(iter.size_hint(), (remaining, Some(remaining)));
+        iter.next();
+    }
+
+    let mut iter = product(0..3, (0..0).resettable());
+    assert_eq!(iter.size_hint(), (0, Some(0)));
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn product_with_double_iterator() {
+    let mut array = [1, 2, 3];
+    let inner = crate::share::DoubleIterator::new(&mut array);
+
+    let iter = product(0..2, inner);
+    assert_eq!(iter.count(), 12);
+}
+
+#[test]
+fn pass_counter_counts() {
+    let mut iter = PassCounter::new(Count { begin: 0, cur: 0, end: 5 });
+    assert_eq!((iter.pass(), iter.items_this_pass()), (0, 0));
+
+    iter.by_ref().take(3).for_each(drop);
+    assert_eq!((iter.pass(), iter.items_this_pass()), (0, 3));
+
+    iter.reset();
+    iter.by_ref().for_each(drop);
+    assert_eq!((iter.pass(), iter.items_this_pass()), (1, 5));
+}
+
+#[test]
+fn pass_counter_runs_the_hook() {
+    let mut passes = Vec::new();
+    let iter = Count { begin: 0, cur: 0, end: 4 };
+    let mut iter = PassCounter::with_hook(iter, |pass, items| passes.push((pass, items)));
+
+    assert_eq!(two_pass_mean_variance(&mut iter), Some((1.5, 1.25)));
+
+    assert_eq!(passes, vec![(0, 0), (1, 4)]);
+}
+
+#[test]
+fn instrument_records_the_calls() {
+    let mut iter = Instrument::new(Count { begin: 0, cur: 0, end: 3 });
+
+    iter.by_ref().for_each(drop);
+    let stats = iter.stats();
+    assert_eq!((stats.items, stats.next_calls, stats.nones), (3, 4, 1));
+
+    // The statistics are kept across resets
+    iter.reset();
+    iter.next();
+    let stats = iter.stats();
+    assert_eq!((stats.items, stats.next_calls, stats.nones), (4, 5, 1));
+
+    iter.reset_stats();
+    assert_eq!(iter.stats(), InstrumentStats::default());
+}
+
+#[test]
+fn instrument_describes_its_stats() {
+    use crate::child::Describe;
+
+    let mut iter = Instrument::new((0..2).resettable());
+    iter.next();
+
+    let description = iter.describe().to_string();
+    assert!(description.starts_with("Instrument(items=1, calls=1, nones=0, time="));
+    assert!(description.ends_with(") <- Restartable <- Range"));
+}
+
+#[test]
+fn reset_to_moves_forward() {
+    let mut iter = Count { begin: 0, cur: 3, end: 10 };
+    iter.reset_to(4);
+    assert_eq!(iter.next(), Some(4));
+
+    let mut iter = (0..10).resettable();
+    iter.reset_to(7);
+    assert_eq!(iter.next(), Some(7));
+    iter.reset_to(0);
+    assert_eq!(iter.next(), Some(0));
+    iter.reset_to(12);
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn multi_peek_peeks_well() {
+    let mut iter = MultiPeek::new(Count { begin: 0, cur: 0, end: 4 });
+
+    assert_eq!(iter.peek_nth(2), Some(&2));
+    assert_eq!(iter.peek(), Some(&0));
+    *iter.peek_mut().unwrap() += 10;
+    assert_eq!(iter.peek(), Some(&2));
+    assert_eq!(iter.peek(), Some(&3));
+    assert_eq!(iter.peek(), None);
+    assert_eq!(iter.peek_nth(4), None);
+    assert_eq!(iter.size_hint(), (4, None));
+
+    assert_eq!(iter.next(), Some(0));
+    assert_eq!(iter.peek(), Some(&11));
+    assert_eq!(iter.collect::<Vec<u32>>(), vec![11, 2, 3]);
+}
+
+#[test]
+fn multi_peek_resets_well() {
+    let mut iter = MultiPeek::new(Count { begin: 0, cur: 0, end: 4 });
+    iter.next();
+    *iter.peek_nth_mut(1).unwrap() = 42;
+
+    iter.reset();
+    assert_eq!(iter.peek(), Some(&0));
+    assert_eq!(collect_twice(&mut iter), vec![0, 1, 2, 3]);
+}
+
+#[test]
+fn put_back_resets_well() {
+    let mut iter = PutBack::new(Count { begin: 0, cur: 0, end: 3 });
+
+    iter.next();
+    iter.put_back(7);
+    assert_eq!(iter.peek_mut(), Some(&mut 7));
+    assert_eq!(iter.size_hint(), (1, None));
+    assert_eq!(iter.next(), Some(7));
+    assert_eq!(iter.peek_mut(), Some(&mut 1));
+
+    iter.put_back(8);
+    iter.reset();
+    assert_eq!(collect_twice(&mut iter), vec![0, 1, 2]);
+}
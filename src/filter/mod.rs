@@ -89,33 +89,72 @@ where
     }
 }
 
-impl<T> crate::ResettableIterator for Exclude<T>
+crate::impl_resettable_iterator!([T] Exclude<T>, cur: T, where T::Item: PartialEq);
+
+impl<T> crate::BidirectionalIterator for Exclude<T>
 where
-    T: crate::ResettableIterator,
+    T: crate::BidirectionalIterator,
     T::Item: PartialEq,
 {
-    fn reset(&mut self) {
-        self.cur.reset();
+    fn prev(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.cur.prev() {
+                // Happens when the iterator is at its first position
+                None    => return None,
+
+                Some(i) => if self.excluded.iter().position(|x| x == &i).is_none() {
+                               return Some(i);
+                },
+            }
+        }
     }
 }
 
-impl<T> crate::child::ChildIterator for Exclude<T>
+impl<T> crate::CheckpointIterator for Exclude<T>
 where
-    T: Iterator,
+    T: crate::CheckpointIterator,
     T::Item: PartialEq,
 {
-    type Parent = T;
+    type Mark = T::Mark;
 
-    fn release_parent(self) -> Self::Parent {
-        self.cur
+    fn checkpoint(&self) -> Self::Mark {
+        self.cur.checkpoint()
     }
 
-    fn get_parent_mut(&mut self) -> &mut Self::Parent {
-        &mut self.cur
+    fn restore(&mut self, mark: Self::Mark) {
+        self.cur.restore(mark);
     }
+}
 
-    fn get_parent(&self) -> &Self::Parent {
-        &self.cur
+crate::impl_child_iterator!([T] Exclude<T>, cur: T, where T::Item: PartialEq);
+
+impl<T> crate::child::Describe for Exclude<T>
+where
+    T: Iterator + crate::child::Describe,
+    T::Item: PartialEq,
+{
+    fn describe_stage(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Exclude(blacklist={})", self.excluded.len())
+    }
+
+    fn describe_parent(&self) -> Option<&dyn crate::child::Describe> {
+        Some(&self.cur)
+    }
+}
+
+impl<T, P> crate::child::WithParent<P> for Exclude<T>
+where
+    T: Iterator,
+    T::Item: PartialEq,
+    P: Iterator<Item = T::Item>,
+{
+    type Output = Exclude<P>;
+
+    fn with_parent(self, parent: P) -> Exclude<P> {
+        Exclude {
+            excluded: self.excluded,
+            cur: parent,
+        }
     }
 }
 
@@ -27,3 +27,33 @@ fn exclude_construction() {
     iter2.force_exclude(3); // should be added now because of the call to `force_exclude`
     assert_eq!(iter2.excluded, vec![3, 5, 3]);
 }
+
+#[test]
+fn exclude_checkpoint() {
+    use crate::{CheckpointIterator, IntoResettable};
+
+    let array = [1, 2, 3, 4, 5];
+    let mut iter = filter::Exclude::with_blacklist(array.iter().cloned().resettable(), vec![2, 4]);
+    iter.next();
+
+    let mark = iter.checkpoint();
+    assert_eq!(iter.next(), Some(3));
+    assert_eq!(iter.next(), Some(5));
+
+    iter.restore(mark);
+    assert_eq!(iter.collect::<Vec<i32>>(), vec![3, 5]);
+}
+
+#[test]
+fn exclude_prev() {
+    use crate::{BidirectionalIterator, SliceCursor};
+
+    let array = [1, 2, 3, 4, 5];
+    let mut iter = filter::Exclude::with_blacklist(SliceCursor::new(&array), vec![&2, &4]);
+
+    iter.by_ref().for_each(drop);
+    assert_eq!(iter.prev(), Some(&5));
+    assert_eq!(iter.prev(), Some(&3));
+    assert_eq!(iter.prev(), Some(&1));
+    assert_eq!(iter.prev(), None);
+}
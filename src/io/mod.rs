@@ -0,0 +1,280 @@
+//! Resettable iterators reading their items from a seekable source like a file
+
+use std::io::{self, BufRead, Read, Seek, SeekFrom};
+
+/// Iterates over the lines of a `BufRead + Seek` reader. Unlike `BufRead::lines`, it can be reset: the reader is seeked back to the offset it had when the iterator was created
+/// 
+/// The lines don't contain their ending `\n` or `\r\n`. Errors are yielded as items, including the ones happening while seeking during a reset or a restore
+/// 
+/// # Example
+/// ```
+/// use iterators_collection::io::ResettableLines;
+/// use iterators_collection::ResettableIterator;
+/// use std::io::Cursor;
+/// 
+/// let mut iter = ResettableLines::new(Cursor::new("first\nsecond\r\nthird")).unwrap();
+/// 
+/// assert_eq!(iter.next().unwrap().unwrap(), "first");
+/// assert_eq!(iter.offset(), 6);
+/// 
+/// iter.reset();
+/// let lines: Vec<String> = iter.map(Result::unwrap).collect();
+/// assert_eq!(lines, vec!["first", "second", "third"]);
+/// ```
+pub struct ResettableLines<R> {
+    reader: R,
+    start: u64,
+    offset: u64,
+    error: Option<io::Error>,
+}
+
+impl<R> ResettableLines<R>
+where
+    R: BufRead + Seek,
+{
+    /// Creates a `ResettableLines` starting at the current offset of `reader`
+    /// 
+    /// # Errors
+    /// Returns the error of the reader if its current offset can't be known
+    pub fn new(mut reader: R) -> io::Result<Self> {
+        let start = reader.stream_position()?;
+
+        Ok(Self {
+            reader,
+            start,
+            offset: start,
+            error: None,
+        })
+    }
+
+    /// Returns the offset in bytes of the next line in the reader
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    /// Returns a reference to the reader
+    pub fn get_ref(&self) -> &R {
+        &self.reader
+    }
+
+    /// Destroys `self` and returns the reader
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+
+    /// Seeks the reader to `offset`, keeping the error to yield it on the next iteration
+    fn seek_to(&mut self, offset: u64) {
+        match self.reader.seek(SeekFrom::Start(offset)) {
+            Ok(_) => {
+                self.offset = offset;
+                self.error = None;
+            },
+
+            Err(error) => self.error = Some(error),
+        }
+    }
+}
+
+impl<R> Iterator for ResettableLines<R>
+where
+    R: BufRead + Seek,
+{
+    type Item = io::Result<String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(error) = self.error.take() {
+            return Some(Err(error));
+        }
+
+        let mut line = String::new();
+        match self.reader.read_line(&mut line) {
+            Ok(0)     => None,
+            Ok(read)  => {
+                self.offset += read as u64;
+
+                if line.ends_with('\n') {
+                    line.pop();
+                    if line.ends_with('\r') {
+                        line.pop();
+                    }
+                }
+
+                Some(Ok(line))
+            },
+            Err(error) => {
+                // The bytes read before the error are consumed, as the whole line on invalid UTF-8
+                if let Ok(offset) = self.reader.stream_position() {
+                    self.offset = offset;
+                }
+
+                Some(Err(error))
+            },
+        }
+    }
+}
+
+crate::impl_root_iterator!([R] ResettableLines<R>);
+
+impl<R> crate::ResettableIterator for ResettableLines<R>
+where
+    R: BufRead + Seek,
+{
+    fn reset(&mut self) {
+        self.seek_to(self.start);
+    }
+}
+
+impl<R> crate::CheckpointIterator for ResettableLines<R>
+where
+    R: BufRead + Seek,
+{
+    type Mark = u64;
+
+    fn checkpoint(&self) -> u64 {
+        self.offset
+    }
+
+    fn restore(&mut self, mark: u64) {
+        self.seek_to(mark);
+    }
+}
+
+/// Iterates over fixed-size binary records of a `Read + Seek` reader. It can be reset: the reader is seeked back to the offset it had when the iterator was created
+/// 
+/// Errors are yielded as items, including the ones happening while seeking during a reset or a restore. A truncated record at the end of the reader is yielded as an error of kind `UnexpectedEof`
+/// 
+/// # Example
+/// ```
+/// use iterators_collection::io::Records;
+/// use iterators_collection::ResettableIterator;
+/// use std::io::Cursor;
+/// 
+/// let mut iter = Records::new(Cursor::new(vec![1, 2, 3, 4, 5, 6]), 2).unwrap();
+/// 
+/// assert_eq!(iter.next().unwrap().unwrap(), vec![1, 2]);
+/// 
+/// iter.reset();
+/// assert_eq!(iter.count(), 3);
+/// ```
+pub struct Records<R> {
+    reader: R,
+    record_size: usize,
+    start: u64,
+    offset: u64,
+    error: Option<io::Error>,
+}
+
+impl<R> Records<R>
+where
+    R: Read + Seek,
+{
+    /// Creates a `Records` iterator starting at the current offset of `reader` and yielding records of `record_size` bytes
+    /// 
+    /// # Errors
+    /// Returns the error of the reader if its current offset can't be known
+    /// 
+    /// # Panics
+    /// Panics if `record_size == 0`
+    pub fn new(mut reader: R, record_size: usize) -> io::Result<Self> {
+        assert_ne!(record_size, 0);
+        let start = reader.stream_position()?;
+
+        Ok(Self {
+            reader,
+            record_size,
+            start,
+            offset: start,
+            error: None,
+        })
+    }
+
+    /// Returns the offset in bytes of the next record in the reader
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    /// Returns a reference to the reader
+    pub fn get_ref(&self) -> &R {
+        &self.reader
+    }
+
+    /// Destroys `self` and returns the reader
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+
+    /// Seeks the reader to `offset`, keeping the error to yield it on the next iteration
+    fn seek_to(&mut self, offset: u64) {
+        match self.reader.seek(SeekFrom::Start(offset)) {
+            Ok(_) => {
+                self.offset = offset;
+                self.error = None;
+            },
+
+            Err(error) => self.error = Some(error),
+        }
+    }
+}
+
+impl<R> Iterator for Records<R>
+where
+    R: Read + Seek,
+{
+    type Item = io::Result<Vec<u8>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(error) = self.error.take() {
+            return Some(Err(error));
+        }
+
+        let mut record = vec![0; self.record_size];
+        let mut filled = 0;
+        while filled < self.record_size {
+            match self.reader.read(&mut record[filled..]) {
+                Ok(0)     => break,
+                Ok(read)  => filled += read,
+                Err(error) if error.kind() == io::ErrorKind::Interrupted => {},
+                Err(error) => {
+                    self.offset += filled as u64;
+                    return Some(Err(error));
+                },
+            }
+        }
+
+        self.offset += filled as u64;
+        match filled {
+            0                               => None,
+            filled if filled < record.len() => Some(Err(io::Error::new(io::ErrorKind::UnexpectedEof, "truncated record"))),
+            _                               => Some(Ok(record)),
+        }
+    }
+}
+
+crate::impl_root_iterator!([R] Records<R>);
+
+impl<R> crate::ResettableIterator for Records<R>
+where
+    R: Read + Seek,
+{
+    fn reset(&mut self) {
+        self.seek_to(self.start);
+    }
+}
+
+impl<R> crate::CheckpointIterator for Records<R>
+where
+    R: Read + Seek,
+{
+    type Mark = u64;
+
+    fn checkpoint(&self) -> u64 {
+        self.offset
+    }
+
+    fn restore(&mut self, mark: u64) {
+        self.seek_to(mark);
+    }
+}
+
+#[cfg(test)]
+mod tests;
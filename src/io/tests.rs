@@ -0,0 +1,125 @@
+//! Unit tests for the io module
+
+use crate::io::*;
+use crate::{CheckpointIterator, ResettableIterator};
+use std::io::{self, BufReader, Cursor, Read, Seek, SeekFrom};
+
+/// A reader failing once when reaching `fail_at`
+struct Failing {
+    inner: Cursor<Vec<u8>>,
+    fail_at: u64,
+    failed: bool,
+}
+
+impl Read for Failing {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let pos = self.inner.position();
+        if self.failed || pos > self.fail_at {
+            return self.inner.read(buf);
+        }
+
+        if pos == self.fail_at {
+            self.failed = true;
+            return Err(io::Error::other("failure"));
+        }
+
+        let len = buf.len().min((self.fail_at - pos) as usize);
+        self.inner.read(&mut buf[..len])
+    }
+}
+
+impl Seek for Failing {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        self.inner.seek(pos)
+    }
+}
+
+#[test]
+fn resettable_lines_starts_at_the_current_offset() {
+    let mut reader = Cursor::new("skipped\nline 1\nline 2\n");
+    reader.seek(SeekFrom::Start(8)).unwrap();
+
+    let mut iter = ResettableLines::new(BufReader::new(reader)).unwrap();
+    assert_eq!(iter.offset(), 8);
+
+    let first: Vec<String> = iter.by_ref().map(Result::unwrap).collect();
+    assert_eq!(first, vec!["line 1", "line 2"]);
+    assert_eq!(iter.offset(), 22);
+
+    iter.reset();
+    let second: Vec<String> = iter.map(Result::unwrap).collect();
+    assert_eq!(first, second);
+}
+
+#[test]
+fn resettable_lines_checkpoint() {
+    let mut iter = ResettableLines::new(Cursor::new("a\nbb\nccc\n")).unwrap();
+    iter.next();
+
+    let mark = iter.checkpoint();
+    assert_eq!(mark, 2);
+    assert_eq!(iter.next().unwrap().unwrap(), "bb");
+    assert_eq!(iter.next().unwrap().unwrap(), "ccc");
+    assert!(iter.next().is_none());
+
+    iter.restore(mark);
+    assert_eq!(iter.next().unwrap().unwrap(), "bb");
+}
+
+#[test]
+fn records_resets_well() {
+    let data: Vec<u8> = (0..12).collect();
+    let mut iter = Records::new(Cursor::new(data), 4).unwrap();
+
+    iter.next();
+    let mark = iter.checkpoint();
+    assert_eq!(iter.next().unwrap().unwrap(), vec![4, 5, 6, 7]);
+
+    iter.restore(mark);
+    assert_eq!(iter.offset(), 4);
+    assert_eq!(iter.by_ref().count(), 2);
+
+    iter.reset();
+    assert_eq!(iter.next().unwrap().unwrap(), vec![0, 1, 2, 3]);
+}
+
+#[test]
+fn records_truncated() {
+    let mut iter = Records::new(Cursor::new(vec![1, 2, 3, 4, 5]), 3).unwrap();
+
+    assert_eq!(iter.next().unwrap().unwrap(), vec![1, 2, 3]);
+    assert_eq!(iter.next().unwrap().unwrap_err().kind(), std::io::ErrorKind::UnexpectedEof);
+    assert!(iter.next().is_none());
+}
+
+#[test]
+fn resettable_lines_offset_after_invalid_utf8() {
+    let mut iter = ResettableLines::new(Cursor::new(b"ok\n\xff\xfe\nlast\n".to_vec())).unwrap();
+
+    assert_eq!(iter.next().unwrap().unwrap(), "ok");
+    assert!(iter.next().unwrap().is_err());
+    assert_eq!(iter.offset(), 6);
+
+    let mark = iter.checkpoint();
+    assert_eq!(iter.next().unwrap().unwrap(), "last");
+    iter.restore(mark);
+    assert_eq!(iter.next().unwrap().unwrap(), "last");
+}
+
+#[test]
+fn records_offset_after_a_failing_read() {
+    let reader = Failing {
+        inner: Cursor::new((0..8).collect()),
+        fail_at: 6,
+        failed: false,
+    };
+    let mut iter = Records::new(reader, 4).unwrap();
+
+    assert_eq!(iter.next().unwrap().unwrap(), vec![0, 1, 2, 3]);
+    assert_eq!(iter.next().unwrap().unwrap_err().kind(), io::ErrorKind::Other);
+    assert_eq!(iter.offset(), 6);
+
+    iter.restore(4);
+    assert_eq!(iter.next().unwrap().unwrap(), vec![4, 5, 6, 7]);
+    assert_eq!(iter.offset(), 8);
+}
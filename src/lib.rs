@@ -8,3 +8,5 @@ mod core;
 pub use crate::core::*;
 
 pub mod child;
+
+pub mod io;
@@ -49,6 +49,7 @@
 /// ```
 pub struct DoubleIterator<'a, T> {
     slice: &'a mut [T],
+    mask: Option<&'a [bool]>,
     first: usize,
     second: usize,
 }
@@ -63,12 +64,88 @@ impl<'a, T> DoubleIterator<'a, T> {
 
         Self {
             slice,
+            mask: None,
 
             first: 0,
             second: 1,
         }
     }
 
+    /// Creates a `DoubleIterator` from a slice, skipping the elements whose value in `mask` is `false`
+    /// 
+    /// A masked out element is neither used as `i` nor as `j`. Its whole line is skipped at once, so inactive elements cost nothing more than a check
+    /// 
+    /// # Example
+    /// ```
+    /// use iterators_collection::share::DoubleIterator;
+    /// 
+    /// let mut array = [1, 2, 3, 4];
+    /// let mask = [true, false, true, true];
+    /// let iter = DoubleIterator::with_mask(&mut array, &mask);
+    /// 
+    /// // 3 active elements give 3 * 2 pairs
+    /// assert_eq!(iter.size_hint(), (6, Some(6)));
+    /// 
+    /// iter.safe_for_each(|i, j| {
+    ///     assert_ne!(*i, 2);
+    ///     assert_ne!(*j, 2);
+    /// });
+    /// ```
+    /// 
+    /// # Notes
+    /// The mask is lost when converting the iterator into a `SingleLineIterator`
+    /// 
+    /// # Panics
+    /// Panics if `mask.len() != slice.len()`
+    pub fn with_mask(slice: &'a mut [T], mask: &'a [bool]) -> Self {
+        assert_eq!(slice.len(), mask.len());
+
+        let mut returned = Self {
+            slice,
+            mask: Some(mask),
+
+            first: 0,
+            second: 0,
+        };
+
+        returned.rewind();
+        returned
+    }
+
+    /// Returns `true` if the `index`th element of the slice is not masked out
+    fn is_active(&self, index: usize) -> bool {
+        match self.mask {
+            None       => true,
+            Some(mask) => mask[index],
+        }
+    }
+
+    /// Returns the number of active elements in `slice[from..to]`
+    fn count_active(&self, from: usize, to: usize) -> usize {
+        match self.mask {
+            None       => to - from,
+            Some(mask) => mask[from..to].iter().filter(|x| **x).count(),
+        }
+    }
+
+    /// Moves the indexes `first` and `second` to the first pair to yield
+    fn rewind(&mut self) {
+        self.first = 0;
+        self.skip_inactive_lines();
+        self.second = self.first;
+
+        if self.first < self.slice.len() {
+            let _ = self.increment();
+        }
+    }
+
+    /// Increments `first` until it reaches an active element or the end of the slice
+    fn skip_inactive_lines(&mut self) {
+        while self.first < self.slice.len() && !self.is_active(self.first) {
+            self.first += 1;
+        }
+    }
+
     /// Returns a mutable pointer to the `index`th element of the borrowed slice
     /// 
     /// # Unsafety
@@ -90,13 +167,52 @@ impl<'a, T> DoubleIterator<'a, T> {
             if self.second == self.slice.len() {
                 self.second = 0;
                 self.first += 1;
+                self.skip_inactive_lines();
 
                 if self.first >= self.slice.len() {
                     return Err(());
                 }
             }
 
-            if self.first != self.second {
+            if self.first != self.second && self.is_active(self.second) {
+                return Ok(());
+            }
+        }
+    }
+
+    /// Decrements the indexes `first` and `second` or returns Err if they already are on the first pair. They are left unchanged in this case
+    fn decrement(&mut self) -> Result<(), ()> {
+        let len = self.slice.len();
+        let (mut first, mut second) = if self.first >= len {
+            (len, 0)
+        } else {
+            (self.first, self.second)
+        };
+
+        loop {
+            // Check for underflow
+            if second == 0 {
+                loop {
+                    if first == 0 {
+                        return Err(());
+                    }
+
+                    first -= 1;
+                    if self.is_active(first) {
+                        break;
+                    }
+                }
+
+                second = len;
+            }
+
+            // Decrement
+            second -= 1;
+
+            if first != second && self.is_active(second) {
+                self.first = first;
+                self.second = second;
+
                 return Ok(());
             }
         }
@@ -138,19 +254,85 @@ impl<'a, T> DoubleIterator<'a, T> {
     /// Panics if either `i` or `j` are out of range (greater or equal to `slice.len()`)
     /// 
     /// Panics if `i == j`
+    /// 
+    /// Panics if either `i` or `j` are masked out
     pub fn set(&mut self, i: usize, j: usize) {
         assert_ne!(i, j);
         assert!(i < self.slice.len() && j < self.slice.len());
+        assert!(self.is_active(i) && self.is_active(j));
 
         self.first = i;
         self.second = j;
     }
 }
 
+crate::impl_root_iterator!(['a, T] DoubleIterator<'a, T>);
+
 impl<T> crate::ResettableIterator for DoubleIterator<'_, T> {
     fn reset(&mut self) {
-        self.first = 0;
-        self.second = 1;
+        self.rewind();
+    }
+
+    /// Resets the iterator and moves it forward so the next call to `next` yields its `n`th item, starting from 0
+    /// 
+    /// The position is computed directly without a mask. With a mask, the iterator is moved forward item by item
+    fn reset_to(&mut self, n: usize) {
+        if self.mask.is_some() {
+            self.rewind();
+            if n > 0 {
+                self.nth(n - 1);
+            }
+
+            return;
+        }
+
+        let len = self.slice.len();
+        let line = len - 1;
+
+        if n >= len * line {
+            self.first = len;
+            self.second = 0;
+        } else {
+            let column = n % line;
+
+            self.first = n / line;
+            self.second = if column < self.first {
+                column
+            } else {
+                column + 1
+            };
+        }
+    }
+}
+
+impl<T> crate::BidirectionalIterator for DoubleIterator<'_, T> {
+    fn prev(&mut self) -> Option<Self::Item> {
+        self.decrement().ok()?;
+
+        unsafe {
+            Some((self.nth_ptr(self.first), self.nth_ptr(self.second)))
+        }
+    }
+}
+
+impl<T> crate::CheckpointIterator for DoubleIterator<'_, T> {
+    type Mark = (usize, usize);
+
+    fn checkpoint(&self) -> (usize, usize) {
+        (self.first, self.second)
+    }
+
+    /// Moves the iterator back to `mark`. A mark whose first index is `slice.len()` means the iterator is exhausted
+    /// 
+    /// # Panics
+    /// Panics if `mark` is not a valid position, under the same conditions as `set`
+    fn restore(&mut self, mark: (usize, usize)) {
+        if mark.0 == self.slice.len() {
+            self.first = mark.0;
+            self.second = 0;
+        } else {
+            self.set(mark.0, mark.1);
+        }
     }
 }
 
@@ -163,10 +345,27 @@ impl<T> Iterator for DoubleIterator<'_, T> {
         }
 
         let returned = Some(unsafe { (self.nth_ptr(self.first), self.nth_ptr(self.second)) });
+        #[allow(dropping_copy_types)]
         std::mem::drop(self.increment()); // Dropping is a way to ignore the error which doesn't matter here
 
         returned
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.slice.len();
+        if self.first >= len {
+            return (0, Some(0));
+        }
+
+        // The pairs left on the current line, then the full lines after it
+        let mut remaining = self.count_active(self.second, len);
+        if self.first > self.second {
+            remaining -= 1;
+        }
+        remaining += self.count_active(self.first + 1, len) * (self.count_active(0, len) - 1);
+
+        (remaining, Some(remaining))
+    }
 }
 
 /// A `DoubleIterator` iterating on one single "line" (see explanation below)
@@ -250,9 +449,63 @@ impl<'a, T> SingleLineIterator<'a, T> {
     }
 }
 
+crate::impl_root_iterator!(['a, T] SingleLineIterator<'a, T>);
+
 impl<T> crate::ResettableIterator for SingleLineIterator<'_, T> {
     fn reset(&mut self) {
-        self.cur = 0;
+        // `cur` must skip `index` like in `new`
+        self.reset_to(0);
+    }
+
+    fn reset_to(&mut self, n: usize) {
+        self.cur = if n < self.index {
+            n
+        } else {
+            n.saturating_add(1).min(self.slice.len())
+        };
+    }
+}
+
+impl<T> crate::BidirectionalIterator for SingleLineIterator<'_, T> {
+    fn prev(&mut self) -> Option<Self::Item> {
+        let mut cur = self.cur.min(self.slice.len());
+        loop {
+            if cur == 0 {
+                return None;
+            }
+
+            cur -= 1;
+            if cur != self.index {
+                break;
+            }
+        }
+
+        self.cur = cur;
+        unsafe {
+            let ptr1 = self.slice.get_unchecked_mut(self.index) as *mut T;
+            let ptr2 = self.slice.get_unchecked_mut(self.cur)   as *mut T;
+
+            Some((ptr1, ptr2))
+        }
+    }
+}
+
+impl<T> crate::CheckpointIterator for SingleLineIterator<'_, T> {
+    type Mark = usize;
+
+    fn checkpoint(&self) -> usize {
+        self.cur.min(self.slice.len())
+    }
+
+    /// Moves the iterator back to `mark`. A mark equal to `slice.len()` means the iterator is exhausted
+    /// 
+    /// # Panics
+    /// Panics if `mark` is greater than `slice.len()`, or if it is equal to the index of the line while smaller than `slice.len()`
+    fn restore(&mut self, mark: usize) {
+        assert!(mark <= self.slice.len());
+        assert!(mark == self.slice.len() || mark != self.index);
+
+        self.cur = mark;
     }
 }
 
@@ -281,7 +534,19 @@ impl<'a, T> Iterator for SingleLineIterator<'a, T> {
 }
 
 impl<'a, T> From<DoubleIterator<'a, T>> for SingleLineIterator<'a, T> {
+    /// Continues the current line of `src`
+    /// 
+    /// The mask of `src` is ignored, so the result may yield inactive elements. If `src` is exhausted, the result is empty until it is reset, and then it iterates over the line of the first element
     fn from(src: DoubleIterator<'a, T>) -> Self {
+        let len = src.slice.len();
+        if src.first >= len {
+            return Self {
+                cur: len,
+                index: 0,
+                slice: src.slice,
+            };
+        }
+
         Self {
             cur: src.second,
             index: src.first,
@@ -291,5 +556,738 @@ impl<'a, T> From<DoubleIterator<'a, T>> for SingleLineIterator<'a, T> {
 }
 
 
+/// The elements of a slice except one, given by `SplitAtEachIterator`
+/// 
+/// It is made of the elements on the left of the excluded one and of the elements on its right, which are both accessible as slices so inner loops can work on contiguous memory
+pub struct Rest<'a, T> {
+    /// The elements before the excluded one
+    pub left: &'a mut [T],
+
+    /// The elements after the excluded one
+    pub right: &'a mut [T],
+}
+
+impl<'a, T> Rest<'a, T> {
+    /// Returns the number of elements in the rest
+    pub fn len(&self) -> usize {
+        self.left.len() + self.right.len()
+    }
+
+    /// Returns `true` if the rest contains no element
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns a reference to the `index`th element of the rest or `None` if out of range. Indexes skip the excluded element
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index < self.left.len() {
+            self.left.get(index)
+        } else {
+            self.right.get(index - self.left.len())
+        }
+    }
+
+    /// Returns a mutable reference to the `index`th element of the rest or `None` if out of range. Indexes skip the excluded element
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index < self.left.len() {
+            self.left.get_mut(index)
+        } else {
+            self.right.get_mut(index - self.left.len())
+        }
+    }
+
+    /// Iterates over the rest, the left part first
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.left.iter().chain(self.right.iter())
+    }
+
+    /// Iterates mutably over the rest, the left part first
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.left.iter_mut().chain(self.right.iter_mut())
+    }
+}
+
+/// Iterates over each element of a slice together with all the other ones
+/// 
+/// At each iteration, it returns a pointer to `slice[i]` and two pointers to the slices `slice[..i]` and `slice[i + 1..]`. Unlike `SingleLineIterator` which gives the other elements one by one, the whole rest is available at once
+/// 
+/// Just like `DoubleIterator` does, this iterator returns raw pointers, so the preferred way to use it is the `safe_for_each` method
+/// 
+/// # Example
+/// ```
+/// use iterators_collection::share::SplitAtEachIterator;
+/// 
+/// let mut array = [1, 2, 3, 4, 5];
+/// let iter = SplitAtEachIterator::new(&mut array);
+/// 
+/// // Collects the sum of the other elements for each one
+/// let mut sums = Vec::new();
+/// iter.safe_for_each(|_i, rest| {
+///     sums.push(rest.left.iter().sum::<i32>() + rest.right.iter().sum::<i32>());
+/// });
+/// 
+/// assert_eq!(sums, vec![14, 13, 12, 11, 10]);
+/// ```
+pub struct SplitAtEachIterator<'a, T> {
+    slice: &'a mut [T],
+    index: usize,
+}
+
+impl<'a, T> SplitAtEachIterator<'a, T> {
+    /// Creates a `SplitAtEachIterator` from a slice
+    pub fn new(slice: &'a mut [T]) -> Self {
+        Self {
+            slice,
+            index: 0,
+        }
+    }
+
+    /// Runs the given closure in a safe context
+    /// 
+    /// # Example
+    /// ```
+    /// use iterators_collection::share::SplitAtEachIterator;
+    /// 
+    /// let mut array = [1, 2, 3, 4, 5];
+    /// let iter = SplitAtEachIterator::new(&mut array);
+    /// 
+    /// iter.safe_for_each(|i, rest| {
+    ///     assert_eq!(rest.len(), 4);
+    ///     assert!(rest.iter().all(|j| j != i));
+    /// });
+    /// ```
+    /// 
+    /// # Notes
+    /// Not like a legacy iteration using a `for` loop, the element and the rest are references because it's safe to use in this context
+    pub fn safe_for_each<F: FnMut(&mut T, Rest<'_, T>)>(self, mut callback: F) {
+        for (i, left, right) in self {
+            unsafe {
+                callback(&mut *i, Rest {
+                    left: &mut *left,
+                    right: &mut *right,
+                });
+            }
+        }
+    }
+}
+
+crate::impl_root_iterator!(['a, T] SplitAtEachIterator<'a, T>);
+
+impl<T> crate::ResettableIterator for SplitAtEachIterator<'_, T> {
+    fn reset(&mut self) {
+        self.index = 0;
+    }
+}
+
+impl<T> crate::CheckpointIterator for SplitAtEachIterator<'_, T> {
+    type Mark = usize;
+
+    fn checkpoint(&self) -> usize {
+        self.index
+    }
+
+    fn restore(&mut self, mark: usize) {
+        self.index = mark;
+    }
+}
+
+impl<T> Iterator for SplitAtEachIterator<'_, T> {
+    type Item = (*mut T, *mut [T], *mut [T]);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let len = self.slice.len();
+        if self.index >= len {
+            return None;
+        }
+
+        let index = self.index;
+        self.index += 1;
+
+        let ptr = self.slice.as_mut_ptr();
+        unsafe {
+            let left = std::ptr::slice_from_raw_parts_mut(ptr, index);
+            let right = std::ptr::slice_from_raw_parts_mut(ptr.add(index + 1), len - index - 1);
+
+            Some((ptr.add(index), left, right))
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.slice.len().saturating_sub(self.index);
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T> ExactSizeIterator for SplitAtEachIterator<'_, T> {}
+
+
+/// The algorithm used by `Permutations` to go from one permutation to the next one
+enum PermutationOrder<T> {
+    /// Heap's algorithm, `stack` being the loop counters of its iterative version
+    Heap {
+        stack: Vec<usize>,
+        index: usize,
+    },
+
+    /// The lexicographic order given by the comparison function
+    Lexicographic(fn(&T, &T) -> std::cmp::Ordering),
+}
+
+/// A saved position of a `Permutations` iterator, returned by its `checkpoint` method
+#[derive(Clone, Debug)]
+pub struct PermutationsMark {
+    order: Vec<usize>,
+    stack: Vec<usize>,
+    index: usize,
+    started: bool,
+    finished: bool,
+}
+
+/// Permutes a slice in place, yielding each permutation
+/// 
+/// By default, the permutations are generated with Heap's algorithm which only does one swap between two permutations. All the `n!` permutations are yielded, the first one being the initial order of the slice
+/// 
+/// Just like `DoubleIterator` does, this iterator returns raw pointers, so the preferred way to use it is the `safe_for_each` method
+/// 
+/// # Example
+/// ```
+/// use iterators_collection::share::Permutations;
+/// 
+/// let mut array = [1, 2, 3];
+/// let mut count = 0;
+/// Permutations::new(&mut array).safe_for_each(|permutation| {
+///     println!("{:?}", permutation);
+///     count += 1;
+/// });
+/// 
+/// assert_eq!(count, 6);
+/// ```
+/// 
+/// # Notes
+/// Resetting the iterator restores the initial order of the slice. If the values are changed through the yielded slices, the changes are kept and only the order is restored
+pub struct Permutations<'a, T> {
+    slice: &'a mut [T],
+    order: Vec<usize>,
+    algorithm: PermutationOrder<T>,
+    started: bool,
+    finished: bool,
+}
+
+impl<'a, T> Permutations<'a, T> {
+    /// Creates a `Permutations` iterator using Heap's algorithm
+    pub fn new(slice: &'a mut [T]) -> Self {
+        let len = slice.len();
+
+        Self {
+            slice,
+            order: (0..len).collect(),
+            algorithm: PermutationOrder::Heap {
+                stack: vec![0; len],
+                index: 1,
+            },
+            started: false,
+            finished: false,
+        }
+    }
+
+    /// Creates a `Permutations` iterator yielding the permutations in lexicographic order, like `next_permutation` does in C++
+    /// 
+    /// The iteration starts from the current order of the slice and stops after the greatest permutation. Sort the slice first to get all of them. Equal elements are not distinguished, so each distinct permutation is yielded once
+    /// 
+    /// # Example
+    /// ```
+    /// use iterators_collection::share::Permutations;
+    /// 
+    /// let mut array = [1, 2, 3];
+    /// let iter = Permutations::lexicographic(&mut array);
+    /// 
+    /// let permutations: Vec<Vec<i32>> = iter.map(|p| unsafe { (*p).to_vec() }).collect();
+    /// assert_eq!(permutations[0], vec![1, 2, 3]);
+    /// assert_eq!(permutations[1], vec![1, 3, 2]);
+    /// assert_eq!(permutations[5], vec![3, 2, 1]);
+    /// ```
+    pub fn lexicographic(slice: &'a mut [T]) -> Self
+    where
+        T: Ord,
+    {
+        let len = slice.len();
+
+        Self {
+            slice,
+            order: (0..len).collect(),
+            algorithm: PermutationOrder::Lexicographic(T::cmp),
+            started: false,
+            finished: false,
+        }
+    }
+
+    /// Runs the given closure in a safe context
+    /// 
+    /// # Notes
+    /// Not like a legacy iteration using a `for` loop, the permutation is given as a reference because it's safe to use in this context
+    pub fn safe_for_each<F: FnMut(&mut [T])>(self, mut callback: F) {
+        for permutation in self {
+            unsafe {
+                callback(&mut *permutation);
+            }
+        }
+    }
+
+    /// Swaps two elements of the slice, keeping track of the original positions
+    fn swap(&mut self, a: usize, b: usize) {
+        self.slice.swap(a, b);
+        self.order.swap(a, b);
+    }
+
+    /// Puts the slice in the next permutation with Heap's algorithm or returns Err
+    fn next_heap(&mut self) -> Result<(), ()> {
+        loop {
+            let (a, b) = match &mut self.algorithm {
+                PermutationOrder::Heap { stack, index } => {
+                    if *index >= stack.len() {
+                        return Err(());
+                    }
+
+                    if stack[*index] < *index {
+                        let swapped = if *index % 2 == 0 {
+                            (0, *index)
+                        } else {
+                            (stack[*index], *index)
+                        };
+
+                        stack[*index] += 1;
+                        *index = 1;
+
+                        swapped
+                    } else {
+                        stack[*index] = 0;
+                        *index += 1;
+                        continue;
+                    }
+                },
+
+                PermutationOrder::Lexicographic(_) => unreachable!(),
+            };
+
+            self.swap(a, b);
+            return Ok(());
+        }
+    }
+
+    /// Puts the slice in the next lexicographic permutation or returns Err
+    fn next_lexicographic(&mut self, compare: fn(&T, &T) -> std::cmp::Ordering) -> Result<(), ()> {
+        use std::cmp::Ordering::Less;
+
+        let len = self.slice.len();
+
+        // The last element of the longest increasing prefix
+        let pivot = (1..len)
+            .rev()
+            .find(|&i| compare(&self.slice[i - 1], &self.slice[i]) == Less)
+            .ok_or(())? - 1;
+
+        // The rightmost element greater than the pivot, which exists because `slice[pivot + 1]` is one
+        let successor = (pivot + 1..len)
+            .rev()
+            .find(|&i| compare(&self.slice[pivot], &self.slice[i]) == Less)
+            .unwrap();
+
+        self.swap(pivot, successor);
+
+        let (mut a, mut b) = (pivot + 1, len - 1);
+        while a < b {
+            self.swap(a, b);
+            a += 1;
+            b -= 1;
+        }
+
+        Ok(())
+    }
+
+    /// Puts the elements back to their original positions
+    fn restore_order(&mut self) {
+        for i in 0..self.order.len() {
+            while self.order[i] != i {
+                let j = self.order[i];
+                self.swap(i, j);
+            }
+        }
+    }
+}
+
+crate::impl_root_iterator!(['a, T] Permutations<'a, T>);
+
+impl<T> crate::ResettableIterator for Permutations<'_, T> {
+    fn reset(&mut self) {
+        self.restore_order();
+
+        if let PermutationOrder::Heap { stack, index } = &mut self.algorithm {
+            for i in stack.iter_mut() {
+                *i = 0;
+            }
+            *index = 1;
+        }
+
+        self.started = false;
+        self.finished = false;
+    }
+}
+
+impl<T> crate::CheckpointIterator for Permutations<'_, T> {
+    type Mark = PermutationsMark;
+
+    fn checkpoint(&self) -> PermutationsMark {
+        let (stack, index) = match &self.algorithm {
+            PermutationOrder::Heap { stack, index } => (stack.clone(), *index),
+            PermutationOrder::Lexicographic(_)      => (Vec::new(), 0),
+        };
+
+        PermutationsMark {
+            order: self.order.clone(),
+            stack,
+            index,
+            started: self.started,
+            finished: self.finished,
+        }
+    }
+
+    /// Sets the iterator back to a position returned by its `checkpoint` method, putting the elements of the slice back in the order they had
+    /// 
+    /// # Panics
+    /// Panics if `mark` has been returned by an iterator on a slice of another length
+    fn restore(&mut self, mark: PermutationsMark) {
+        self.restore_order();
+        apply_permutation(self.slice, &mark.order);
+        self.order = mark.order;
+
+        if let PermutationOrder::Heap { stack, index } = &mut self.algorithm {
+            *stack = mark.stack;
+            *index = mark.index;
+        }
+
+        self.started = mark.started;
+        self.finished = mark.finished;
+    }
+}
+
+impl<T> Iterator for Permutations<'_, T> {
+    type Item = *mut [T];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+
+        if self.started {
+            let moved = match self.algorithm {
+                PermutationOrder::Heap { .. }            => self.next_heap(),
+                PermutationOrder::Lexicographic(compare) => self.next_lexicographic(compare),
+            };
+
+            if moved.is_err() {
+                self.finished = true;
+                return None;
+            }
+        }
+
+        self.started = true;
+        Some(&mut *self.slice as *mut [T])
+    }
+}
+
+
+/// Iterates over the disjoint cycles of a permutation
+/// 
+/// The permutation is given as a slice of indexes containing each number of `0..permutation.len()` exactly once. Each cycle is yielded as the list of the indexes it goes through, starting from its smallest one. Fixed points are yielded as cycles of length one
+/// 
+/// # Example
+/// ```
+/// use iterators_collection::share::Cycles;
+/// 
+/// let permutation = [2, 0, 1, 3];
+/// let cycles: Vec<Vec<usize>> = Cycles::new(&permutation).collect();
+/// 
+/// assert_eq!(cycles, vec![vec![0, 2, 1], vec![3]]);
+/// ```
+/// 
+/// # Panics
+/// The iteration panics if `permutation` is not a permutation of `0..permutation.len()`
+pub struct Cycles<'a> {
+    permutation: &'a [usize],
+    visited: Vec<bool>,
+    start: usize,
+}
+
+impl<'a> Cycles<'a> {
+    /// Creates a `Cycles` iterator from a permutation
+    pub fn new(permutation: &'a [usize]) -> Self {
+        Self {
+            permutation,
+            visited: vec![false; permutation.len()],
+            start: 0,
+        }
+    }
+}
+
+crate::impl_root_iterator!(['a] Cycles<'a>);
+
+impl crate::ResettableIterator for Cycles<'_> {
+    fn reset(&mut self) {
+        for i in self.visited.iter_mut() {
+            *i = false;
+        }
+        self.start = 0;
+    }
+}
+
+impl crate::CheckpointIterator for Cycles<'_> {
+    type Mark = (Vec<bool>, usize);
+
+    fn checkpoint(&self) -> (Vec<bool>, usize) {
+        (self.visited.clone(), self.start)
+    }
+
+    /// Moves the iterator back to `mark`
+    /// 
+    /// # Panics
+    /// Panics if `mark` was not created from a permutation of the same length
+    fn restore(&mut self, mark: (Vec<bool>, usize)) {
+        assert_eq!(mark.0.len(), self.permutation.len());
+
+        self.visited = mark.0;
+        self.start = mark.1;
+    }
+}
+
+impl Iterator for Cycles<'_> {
+    type Item = Vec<usize>;
+
+    fn next(&mut self) -> Option<Vec<usize>> {
+        while self.start < self.permutation.len() && self.visited[self.start] {
+            self.start += 1;
+        }
+
+        if self.start == self.permutation.len() {
+            return None;
+        }
+
+        let mut cycle = Vec::new();
+        let mut cur = self.start;
+        loop {
+            assert!(!self.visited[cur], "not a permutation");
+
+            self.visited[cur] = true;
+            cycle.push(cur);
+
+            cur = self.permutation[cur];
+            assert!(cur < self.permutation.len(), "not a permutation");
+
+            if cur == self.start {
+                return Some(cycle);
+            }
+        }
+    }
+}
+
+/// Reorders `slice` so that its `i`th element becomes the one previously at `permutation[i]`
+/// 
+/// It walks the cycles of the permutation with `Cycles` and only swaps elements, allocating one flag per element. See `apply_permutation_in_place` to avoid this allocation
+/// 
+/// # Example
+/// ```
+/// use iterators_collection::share::apply_permutation;
+/// 
+/// let mut array = ['a', 'b', 'c', 'd'];
+/// apply_permutation(&mut array, &[2, 0, 1, 3]);
+/// 
+/// assert_eq!(array, ['c', 'a', 'b', 'd']);
+/// ```
+/// 
+/// # Panics
+/// Panics if `slice.len() != permutation.len()`
+/// 
+/// Panics if `permutation` is not a permutation of `0..permutation.len()`
+pub fn apply_permutation<T>(slice: &mut [T], permutation: &[usize]) {
+    assert_eq!(slice.len(), permutation.len());
+
+    for cycle in Cycles::new(permutation) {
+        for pair in cycle.windows(2) {
+            slice.swap(pair[0], pair[1]);
+        }
+    }
+}
+
+/// Reorders `slice` so that its `i`th element becomes the one previously at `permutation[i]`, using O(1) extra memory
+/// 
+/// It does the same as `apply_permutation` but the visited elements are marked by temporarily flipping the bits of the indexes inside `permutation`. The permutation is restored before returning
+/// 
+/// # Example
+/// ```
+/// use iterators_collection::share::apply_permutation_in_place;
+/// 
+/// let mut array = ['a', 'b', 'c', 'd'];
+/// let mut permutation = [2, 0, 1, 3];
+/// apply_permutation_in_place(&mut array, &mut permutation);
+/// 
+/// assert_eq!(array, ['c', 'a', 'b', 'd']);
+/// assert_eq!(permutation, [2, 0, 1, 3]);
+/// ```
+/// 
+/// # Panics
+/// Panics if `slice.len() != permutation.len()`
+/// 
+/// Panics if `permutation` is not a permutation of `0..permutation.len()`. In this case, the content of both `slice` and `permutation` is unspecified
+pub fn apply_permutation_in_place<T>(slice: &mut [T], permutation: &mut [usize]) {
+    assert_eq!(slice.len(), permutation.len());
+
+    let len = permutation.len();
+    assert!(permutation.iter().all(|index| *index < len), "not a permutation");
+
+    // A slice can't have more than `isize::MAX` elements so the flipped indexes can't be mistaken for valid ones
+    let is_marked = |index: usize| index >= len;
+
+    for start in 0..len {
+        if is_marked(permutation[start]) {
+            continue;
+        }
+
+        let mut cur = start;
+        loop {
+            let next = permutation[cur];
+            permutation[cur] = !next;
+
+            if next == start {
+                break;
+            }
+
+            assert!(!is_marked(permutation[next]), "not a permutation");
+            slice.swap(cur, next);
+            cur = next;
+        }
+    }
+
+    for i in permutation.iter_mut() {
+        *i = !*i;
+    }
+}
+
+
+/// The error returned by `DisjointIterator::new` when the indexes can't be borrowed simultaneously
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DisjointIndexError {
+    /// `index` is greater or equal to the length `len` of the slice
+    OutOfRange {
+        index: usize,
+        len: usize,
+    },
+
+    /// `index` is requested more than once
+    Duplicate(usize),
+}
+
+impl std::fmt::Display for DisjointIndexError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DisjointIndexError::OutOfRange { index, len } => write!(f, "index {} is out of range for a slice of length {}", index, len),
+            DisjointIndexError::Duplicate(index)          => write!(f, "index {} is requested more than once", index),
+        }
+    }
+}
+
+impl std::error::Error for DisjointIndexError {}
+
+/// Yields mutable references to several elements of a slice at the same time
+/// 
+/// The indexes are checked to be distinct when the iterator is created, so all the references can be alive together, without any `split_at_mut` call nor unsafe code. They are yielded in the order of the indexes
+/// 
+/// # Example
+/// ```
+/// use iterators_collection::share::DisjointIterator;
+/// 
+/// let mut array = [1, 2, 3, 4, 5];
+/// let mut iter = DisjointIterator::new(&mut array, &[4, 0, 2]).unwrap();
+/// 
+/// let a = iter.next().unwrap();
+/// let b = iter.next().unwrap();
+/// let c = iter.next().unwrap();
+/// std::mem::swap(a, b);
+/// *c += *a + *b;
+/// 
+/// assert_eq!(array, [5, 2, 9, 4, 1]);
+/// ```
+pub struct DisjointIterator<'a, 'b, T> {
+    slice: *mut T,
+    indexes: std::slice::Iter<'b, usize>,
+    _marker: std::marker::PhantomData<&'a mut T>,
+}
+
+impl<'a, 'b, T> DisjointIterator<'a, 'b, T> {
+    /// Creates a `DisjointIterator` yielding `slice[indexes[0]]`, `slice[indexes[1]]`...
+    /// 
+    /// The indexes are checked by sorting a copy of them, which costs `O(k log k)` for `k` indexes
+    /// 
+    /// # Errors
+    /// Returns `DisjointIndexError::OutOfRange` if an index is greater or equal to `slice.len()`
+    /// 
+    /// Returns `DisjointIndexError::Duplicate` if an index is present more than once
+    pub fn new(slice: &'a mut [T], indexes: &'b [usize]) -> Result<Self, DisjointIndexError> {
+        let mut sorted = indexes.to_vec();
+        sorted.sort_unstable();
+
+        if let Some(&index) = sorted.last() {
+            if index >= slice.len() {
+                return Err(DisjointIndexError::OutOfRange {
+                    index,
+                    len: slice.len(),
+                });
+            }
+        }
+
+        if let Some(pair) = sorted.windows(2).find(|pair| pair[0] == pair[1]) {
+            return Err(DisjointIndexError::Duplicate(pair[0]));
+        }
+
+        Ok(Self {
+            slice: slice.as_mut_ptr(),
+            indexes: indexes.iter(),
+            _marker: std::marker::PhantomData,
+        })
+    }
+}
+
+crate::impl_root_iterator!(['a, 'b, T] DisjointIterator<'a, 'b, T>);
+
+impl<'a, T> Iterator for DisjointIterator<'a, '_, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<&'a mut T> {
+        let index = *self.indexes.next()?;
+
+        // The indexes are distinct and in range, so each element is borrowed once
+        unsafe {
+            Some(&mut *self.slice.add(index))
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.indexes.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for DisjointIterator<'_, '_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let index = *self.indexes.next_back()?;
+
+        unsafe {
+            Some(&mut *self.slice.add(index))
+        }
+    }
+}
+
+impl<T> ExactSizeIterator for DisjointIterator<'_, '_, T> {}
+
+impl<T> std::iter::FusedIterator for DisjointIterator<'_, '_, T> {}
+
+
 #[cfg(test)]
 mod tests;
@@ -93,6 +93,62 @@ fn double_iterator_set_panics_for_overflow() {
     iter.set(5, 4);
 }
 
+#[test]
+fn double_iterator_size_hint() {
+    let mut array = [1, 2, 3, 4, 5];
+    let mut iter = DoubleIterator::new(&mut array);
+
+    for remaining in (0..=20).rev() {
+        assert_eq!(iter.size_hint(), (remaining, Some(remaining)));
+        iter.next();
+    }
+}
+
+#[test]
+fn double_iterator_with_mask_skips_inactive_elements() {
+    let mut array = [0, 1, 2, 3, 4];
+    let mask = [false, true, false, true, true];
+    let mut count = [0; 5];
+    let iter = DoubleIterator::with_mask(&mut array, &mask);
+
+    for (i, j) in iter {
+        unsafe {
+            assert!(mask[*i] && mask[*j]);
+            assert_ne!(*i, *j);
+            count[*i] += 1;
+        }
+    }
+
+    assert_eq!(count, [0, 2, 0, 2, 2]);
+}
+
+#[test]
+fn double_iterator_with_mask_size_hint() {
+    let mut array = [0, 1, 2, 3, 4, 5];
+    let mask = [true, false, true, false, false, true];
+    let mut iter = DoubleIterator::with_mask(&mut array, &mask);
+
+    let mut remaining = 6;
+    while let Some(_) = iter.next() {
+        remaining -= 1;
+        assert_eq!(iter.size_hint(), (remaining, Some(remaining)));
+    }
+    assert_eq!(remaining, 0);
+
+    iter.reset();
+    assert_eq!(iter.size_hint(), (6, Some(6)));
+}
+
+#[test]
+fn double_iterator_with_mask_too_few_active_elements() {
+    let mut array = [1, 2, 3];
+    let mask = [false, true, false];
+    let mut iter = DoubleIterator::with_mask(&mut array, &mask);
+
+    assert_eq!(iter.size_hint(), (0, Some(0)));
+    assert!(iter.next().is_none());
+}
+
 #[test]
 fn single_line_iterator_iterates_well() {
     let mut slice = [1, 2, 3, 4, 5];
@@ -123,6 +179,7 @@ fn single_line_iterator_from_double_iterator_works() {
     iter.set(0, 3);
 
     let iter = SingleLineIterator::from(iter);
+    #[allow(clippy::useless_vec)]
     let expected = vec![(1, 4), (1, 5)];
 
     let mut last_n = 0;
@@ -148,3 +205,467 @@ fn single_line_iterator_safe_for_each() {
         assert_ne!(i, j);
     });
 }
+
+#[test]
+fn split_at_each_iterator_gives_the_rest() {
+    let mut array = [1, 2, 3, 4, 5];
+    let iter = SplitAtEachIterator::new(&mut array);
+
+    for (n, (i, left, right)) in iter.enumerate() {
+        unsafe {
+            assert_eq!(*i, n + 1);
+            assert_eq!(&*left, &[1, 2, 3, 4, 5][..n]);
+            assert_eq!(&*right, &[1, 2, 3, 4, 5][n + 1..]);
+        }
+    }
+}
+
+#[test]
+fn split_at_each_iterator_safe_for_each() {
+    let mut array = [1, 2, 3, 4, 5];
+    let iter = SplitAtEachIterator::new(&mut array);
+
+    iter.safe_for_each(|i, mut rest| {
+        assert_eq!(rest.len(), 4);
+        for j in rest.iter_mut() {
+            *j += *i;
+        }
+    });
+
+    assert_eq!(array, [57, 56, 53, 46, 31]);
+}
+
+#[test]
+fn split_at_each_iterator_reset() {
+    let mut array = [1, 2, 3];
+    let mut iter = SplitAtEachIterator::new(&mut array);
+
+    while iter.next().is_some() {}
+    assert_eq!(iter.len(), 0);
+
+    iter.reset();
+    assert_eq!(iter.len(), 3);
+}
+
+#[test]
+fn rest_get() {
+    let mut left = [1, 2];
+    let mut right = [4, 5];
+    let mut rest = Rest {
+        left: &mut left,
+        right: &mut right,
+    };
+
+    assert_eq!(rest.get(1), Some(&2));
+    assert_eq!(rest.get(2), Some(&4));
+    assert_eq!(rest.get(4), None);
+
+    *rest.get_mut(3).unwrap() = 6;
+    assert_eq!(right, [4, 6]);
+}
+
+#[test]
+fn permutations_yields_all_the_permutations() {
+    let mut array = [1, 2, 3, 4];
+    let mut seen = Vec::new();
+    let iter = Permutations::new(&mut array);
+
+    iter.safe_for_each(|permutation| {
+        assert!(!seen.contains(&permutation.to_vec()));
+        seen.push(permutation.to_vec());
+    });
+
+    assert_eq!(seen.len(), 24);
+    assert_eq!(seen[0], vec![1, 2, 3, 4]);
+}
+
+#[test]
+fn permutations_reset_restores_the_order() {
+    let mut array = [1, 2, 3, 4, 5];
+    let mut iter = Permutations::new(&mut array);
+
+    for _ in 0..37 {
+        iter.next();
+    }
+
+    iter.reset();
+    let first = iter.next().unwrap();
+    unsafe {
+        assert_eq!(&*first, &[1, 2, 3, 4, 5]);
+    }
+
+    // Once fully consumed, it can be reset too
+    while iter.next().is_some() {}
+    iter.reset();
+    assert_eq!(iter.by_ref().count(), 120);
+
+    iter.reset();
+    drop(iter);
+    assert_eq!(array, [1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn permutations_lexicographic() {
+    let mut array = [3, 1, 2];
+    let mut iter = Permutations::lexicographic(&mut array);
+    let mut seen = Vec::new();
+
+    for permutation in &mut iter {
+        unsafe {
+            seen.push((*permutation).to_vec());
+        }
+    }
+    assert_eq!(seen, vec![vec![3, 1, 2], vec![3, 2, 1]]);
+
+    iter.reset();
+    drop(iter);
+    assert_eq!(array, [3, 1, 2]);
+}
+
+#[test]
+fn permutations_lexicographic_with_duplicates() {
+    let mut array = [1, 1, 2];
+    let iter = Permutations::lexicographic(&mut array);
+
+    assert_eq!(iter.count(), 3);
+}
+
+#[test]
+fn permutations_of_empty_slice() {
+    let mut array: [i32; 0] = [];
+    let iter = Permutations::new(&mut array);
+
+    assert_eq!(iter.count(), 1);
+}
+
+#[test]
+fn cycles_iterates_well() {
+    let permutation = [3, 4, 0, 2, 1, 5];
+    let mut iter = Cycles::new(&permutation);
+
+    assert_eq!(iter.next(), Some(vec![0, 3, 2]));
+    assert_eq!(iter.next(), Some(vec![1, 4]));
+    assert_eq!(iter.next(), Some(vec![5]));
+    assert_eq!(iter.next(), None);
+
+    iter.reset();
+    assert_eq!(iter.count(), 3);
+}
+
+#[test]
+fn cycles_checkpoint() {
+    use crate::CheckpointIterator;
+
+    let permutation = [3, 4, 0, 2, 1, 5];
+    let mut iter = Cycles::new(&permutation);
+    iter.next();
+
+    let mark = iter.checkpoint();
+    assert_eq!(iter.next(), Some(vec![1, 4]));
+    assert_eq!(iter.next(), Some(vec![5]));
+
+    iter.restore(mark);
+    assert_eq!(iter.collect::<Vec<Vec<usize>>>(), vec![vec![1, 4], vec![5]]);
+}
+
+#[test]
+#[should_panic]
+fn cycles_panics_for_invalid_permutation() {
+    let permutation = [1, 1, 0];
+    Cycles::new(&permutation).for_each(drop);
+}
+
+#[test]
+fn apply_permutation_both_ways_agree() {
+    let permutation = [3, 4, 0, 2, 1, 5];
+    let mut array1 = [10, 11, 12, 13, 14, 15];
+    let mut array2 = array1;
+
+    apply_permutation(&mut array1, &permutation);
+
+    let mut permutation_copy = permutation;
+    apply_permutation_in_place(&mut array2, &mut permutation_copy);
+
+    assert_eq!(array1, [13, 14, 10, 12, 11, 15]);
+    assert_eq!(array1, array2);
+    assert_eq!(permutation, permutation_copy);
+}
+
+#[test]
+fn apply_permutation_in_place_panics_for_invalid_permutation() {
+    use std::panic::catch_unwind;
+
+    let duplicate = catch_unwind(|| {
+        let mut array = [1, 2, 3];
+        let mut permutation = [1, 2, 1];
+        apply_permutation_in_place(&mut array, &mut permutation);
+    });
+    assert!(duplicate.is_err());
+
+    let out_of_range = catch_unwind(|| {
+        let mut array = [10, 20];
+        let mut permutation = [5, 1];
+        apply_permutation_in_place(&mut array, &mut permutation);
+    });
+    assert!(out_of_range.is_err());
+}
+
+#[test]
+fn disjoint_iterator_yields_in_request_order() {
+    let mut array = [10, 11, 12, 13, 14];
+    let iter = DisjointIterator::new(&mut array, &[3, 1, 4]).unwrap();
+    assert_eq!(iter.len(), 3);
+
+    let references: Vec<&mut i32> = iter.collect();
+    assert_eq!(references, vec![&mut 13, &mut 11, &mut 14]);
+
+    for (n, i) in references.into_iter().enumerate() {
+        *i = n as i32;
+    }
+    assert_eq!(array, [10, 1, 12, 0, 2]);
+}
+
+#[test]
+fn disjoint_iterator_errors() {
+    let mut array = [1, 2, 3];
+
+    assert_eq!(
+        DisjointIterator::new(&mut array, &[0, 2, 0]).err(),
+        Some(DisjointIndexError::Duplicate(0)),
+    );
+    assert_eq!(
+        DisjointIterator::new(&mut array, &[1, 3]).err(),
+        Some(DisjointIndexError::OutOfRange { index: 3, len: 3 }),
+    );
+    assert!(DisjointIterator::new(&mut array, &[]).is_ok());
+}
+
+#[test]
+fn double_iterator_checkpoint() {
+    use crate::CheckpointIterator;
+
+    let mut array = [1, 2, 3, 4, 5];
+    let mut iter = DoubleIterator::new(&mut array);
+
+    for _ in 0..7 {
+        iter.next();
+    }
+
+    let mark = iter.checkpoint();
+    let expected: Vec<(*mut i32, *mut i32)> = iter.by_ref().take(3).collect();
+
+    iter.restore(mark);
+    assert_eq!(iter.take(3).collect::<Vec<(*mut i32, *mut i32)>>(), expected);
+}
+
+#[test]
+fn single_line_iterator_from_masked_double_iterator() {
+    let mut array = [1, 2, 3];
+    let mask = [true, false, false];
+    let iter = DoubleIterator::with_mask(&mut array, &mask);
+
+    let mut iter = SingleLineIterator::from(iter);
+    assert!(iter.next().is_none());
+
+    let mut array: [i32; 0] = [];
+    let iter = DoubleIterator::with_mask(&mut array, &[]);
+
+    let mut iter = SingleLineIterator::from(iter);
+    assert!(iter.next().is_none());
+}
+
+#[test]
+fn single_line_iterator_from_consumed_double_iterator() {
+    use crate::BidirectionalIterator;
+
+    let mut array = [1, 2, 3];
+    let mut iter = DoubleIterator::new(&mut array);
+    iter.by_ref().for_each(drop);
+
+    let mut iter = SingleLineIterator::from(iter);
+    assert!(iter.next().is_none());
+    assert!(iter.prev().is_some());
+}
+
+#[test]
+#[should_panic]
+fn double_iterator_restore_panics_with_same_values() {
+    use crate::CheckpointIterator;
+
+    let mut array = [1, 2, 3, 4, 5];
+    let mut iter = DoubleIterator::new(&mut array);
+
+    iter.restore((1, 1));
+}
+
+#[test]
+#[should_panic]
+fn double_iterator_restore_panics_for_overflow() {
+    use crate::CheckpointIterator;
+
+    let mut array = [1, 2, 3, 4, 5];
+    let mut iter = DoubleIterator::new(&mut array);
+
+    iter.restore((0, 99));
+}
+
+#[test]
+fn double_iterator_restore_exhausted() {
+    use crate::CheckpointIterator;
+
+    let mut array = [1, 2, 3];
+    let mut iter = DoubleIterator::new(&mut array);
+
+    iter.by_ref().for_each(drop);
+    let mark = iter.checkpoint();
+
+    iter.reset();
+    iter.restore(mark);
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+#[should_panic]
+fn single_line_iterator_restore_panics_on_the_line_index() {
+    use crate::CheckpointIterator;
+
+    let mut array = [1, 2, 3, 4, 5];
+    let mut iter = SingleLineIterator::new(&mut array, 1);
+
+    iter.restore(1);
+}
+
+#[test]
+#[should_panic]
+fn single_line_iterator_restore_panics_for_overflow() {
+    use crate::CheckpointIterator;
+
+    let mut array = [1, 2, 3, 4, 5];
+    let mut iter = SingleLineIterator::new(&mut array, 1);
+
+    iter.restore(6);
+}
+
+#[test]
+fn single_line_iterator_restore_exhausted() {
+    use crate::CheckpointIterator;
+
+    let mut array = [1, 2, 3];
+    let mut iter = SingleLineIterator::new(&mut array, 2);
+
+    iter.by_ref().for_each(drop);
+    let mark = iter.checkpoint();
+
+    iter.reset();
+    iter.restore(mark);
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn permutations_checkpoint_restores_the_order() {
+    use crate::CheckpointIterator;
+
+    let mut array = [1, 2, 3, 4];
+    let mut iter = Permutations::new(&mut array);
+
+    for _ in 0..5 {
+        iter.next();
+    }
+
+    let mark = iter.checkpoint();
+    let expected: Vec<Vec<i32>> = iter.by_ref().take(4).map(|p| unsafe { (*p).to_vec() }).collect();
+
+    iter.restore(mark);
+    let restored: Vec<Vec<i32>> = iter.by_ref().take(4).map(|p| unsafe { (*p).to_vec() }).collect();
+    assert_eq!(restored, expected);
+
+    // The whole iteration still yields each permutation once
+    iter.reset();
+    assert_eq!(iter.count(), 24);
+}
+
+#[test]
+fn double_iterator_prev_goes_back() {
+    use crate::BidirectionalIterator;
+
+    let mut array = [0, 1, 2, 3];
+    let mask = [true, false, true, true];
+    let mut iter = DoubleIterator::with_mask(&mut array, &mask);
+
+    let forward: Vec<(*mut i32, *mut i32)> = iter.by_ref().collect();
+    let mut backward = Vec::new();
+    while let Some(pair) = iter.prev() {
+        backward.push(pair);
+    }
+    backward.reverse();
+
+    assert_eq!(forward.len(), 6);
+    assert_eq!(forward, backward);
+    assert!(iter.prev().is_none());
+    assert_eq!(iter.next(), Some(forward[0]));
+}
+
+#[test]
+fn single_line_iterator_prev_goes_back() {
+    use crate::BidirectionalIterator;
+
+    let mut array = [0, 1, 2, 3];
+    let mut iter = SingleLineIterator::new(&mut array, 1);
+
+    iter.next();
+    iter.next();
+    let (_, j) = iter.prev().unwrap();
+    unsafe {
+        assert_eq!(*j, 2);
+    }
+
+    let (_, j) = iter.prev().unwrap();
+    unsafe {
+        assert_eq!(*j, 0);
+    }
+
+    assert!(iter.prev().is_none());
+}
+
+#[test]
+fn double_iterator_reset_to() {
+    let mut array = [1, 2, 3, 4, 5];
+    let mut iter = DoubleIterator::new(&mut array);
+
+    let all: Vec<(*mut i32, *mut i32)> = iter.by_ref().collect();
+    for n in 0..=all.len() + 1 {
+        iter.reset_to(n);
+        assert_eq!(iter.next(), all.get(n).cloned());
+    }
+}
+
+#[test]
+fn double_iterator_reset_to_with_mask() {
+    let mut array = [1, 2, 3, 4, 5];
+    let mask = [true, true, false, true, false];
+    let mut iter = DoubleIterator::with_mask(&mut array, &mask);
+
+    let all: Vec<(*mut i32, *mut i32)> = iter.by_ref().collect();
+    for n in 0..=all.len() {
+        iter.reset_to(n);
+        assert_eq!(iter.next(), all.get(n).cloned());
+    }
+}
+
+#[test]
+fn single_line_iterator_reset_to() {
+    let mut array = [1, 2, 3, 4, 5];
+
+    for index in 0..array.len() {
+        let mut iter = SingleLineIterator::new(&mut array, index);
+
+        let all: Vec<(*mut i32, *mut i32)> = iter.by_ref().collect();
+        for n in 0..=all.len() {
+            iter.reset_to(n);
+            assert_eq!(iter.next(), all.get(n).cloned());
+        }
+
+        iter.reset();
+        assert_eq!(iter.next(), all.first().cloned());
+    }
+}